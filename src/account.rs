@@ -0,0 +1,227 @@
+use alloy::primitives::{Address, Bytes, U256};
+use alloy::sol;
+use alloy::sol_types::{sol_data, SolCall, SolType};
+use anyhow::Result;
+
+use crate::clone::clone_init_code;
+use crate::config::{AccountPreset, CloneKind, SaltRecipe};
+
+sol! {
+    function initialize(address anOwner);
+}
+
+/// Counterfactual smart account deployed by an ERC-4337 account factory.
+///
+/// The mined value is the `salt`/`nonce` argument of the factory's `createAccount`.
+#[derive(Clone, Debug)]
+pub struct SmartAccount {
+    pub preset: AccountPreset,
+    pub implementation: Address,
+    pub owners: Vec<Address>,
+    /// Account initialization call (Kernel's `initialize` data, or the proxy's init data)
+    pub init_call: Bytes,
+    /// Creation code of the factory's proxy contract, for factories that deploy it with `new`
+    pub proxy_creation_code: Bytes,
+}
+
+impl SmartAccount {
+    /// Check that the inputs the preset needs are present
+    pub fn validate(&self) -> Result<()> {
+        let single_owner = matches!(self.preset, AccountPreset::Simple | AccountPreset::Light);
+        if single_owner && self.owners.len() != 1 {
+            anyhow::bail!(
+                "The {} preset takes exactly one --owner",
+                self.preset.name()
+            );
+        }
+        if self.preset == AccountPreset::Coinbase && self.owners.is_empty() {
+            anyhow::bail!("The coinbase preset requires at least one --owner");
+        }
+        if matches!(self.preset, AccountPreset::Kernel | AccountPreset::Custom)
+            && self.init_call.is_empty()
+        {
+            anyhow::bail!("The {} preset requires --init-call", self.preset.name());
+        }
+        if matches!(self.preset, AccountPreset::Simple | AccountPreset::Custom)
+            && self.proxy_creation_code.is_empty()
+        {
+            anyhow::bail!(
+                "The {} preset requires --proxy-creation-code",
+                self.preset.name()
+            );
+        }
+        Ok(())
+    }
+
+    /// Data passed to the proxy constructor, or empty for Solady proxies initialized afterwards
+    pub fn proxy_init_data(&self) -> Vec<u8> {
+        match self.preset {
+            AccountPreset::Simple => initializeCall {
+                anOwner: self.owners[0],
+            }
+            .abi_encode(),
+            AccountPreset::Custom => self.init_call.to_vec(),
+            AccountPreset::Light | AccountPreset::Kernel | AccountPreset::Coinbase => Vec::new(),
+        }
+    }
+
+    /// Init code the factory deploys with CREATE2
+    pub fn init_code(&self) -> Vec<u8> {
+        match self.preset {
+            // new ERC1967Proxy{salt: salt}(implementation, initData)
+            AccountPreset::Simple | AccountPreset::Custom => {
+                let mut init_code = self.proxy_creation_code.to_vec();
+                init_code.extend_from_slice(
+                    &<(sol_data::Address, sol_data::Bytes)>::abi_encode_params(&(
+                        self.implementation,
+                        Bytes::from(self.proxy_init_data()),
+                    )),
+                );
+                init_code
+            }
+            AccountPreset::Light | AccountPreset::Kernel | AccountPreset::Coinbase => {
+                clone_init_code(CloneKind::Erc1967, &self.implementation, None)
+                    .expect("clones without immutable args always fit")
+            }
+        }
+    }
+
+    /// How the factory turns the `createAccount` salt into the CREATE2 salt
+    pub fn salt_recipe(&self) -> SaltRecipe {
+        match self.preset {
+            AccountPreset::Simple | AccountPreset::Custom => SaltRecipe::Raw,
+            // keccak256(abi.encodePacked(owner, salt))
+            AccountPreset::Light => SaltRecipe::packed_deployer(&self.owners[0]),
+            // keccak256(abi.encodePacked(data, salt))
+            AccountPreset::Kernel => SaltRecipe::prefixed(&self.init_call),
+            // keccak256(abi.encode(owners, nonce)): the nonce word sits between the
+            // array offset and the array itself
+            AccountPreset::Coinbase => {
+                let owners = self
+                    .owners
+                    .iter()
+                    .map(|owner| Bytes::from(owner.into_word().to_vec()))
+                    .collect::<Vec<_>>();
+                let encoded =
+                    <(sol_data::Array<sol_data::Bytes>, sol_data::Uint<256>)>::abi_encode_params(
+                        &(owners, U256::ZERO),
+                    );
+                SaltRecipe::Hashed {
+                    prefix: encoded[..32].to_vec(),
+                    suffix: encoded[64..].to_vec(),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::{get_create2_address, AddressDeriver, CREATE3_PROXY_INITCODE_HASH};
+    use crate::config::{MinerConfig, MiningMode};
+    use crate::pattern::AddressPattern;
+    use crate::salt::SaltTemplate;
+    use alloy::primitives::{address, bytes, keccak256, B256};
+
+    fn account(preset: AccountPreset, owners: Vec<Address>) -> SmartAccount {
+        SmartAccount {
+            preset,
+            implementation: address!("00000000000000000000000000000000000000aa"),
+            owners,
+            init_call: Bytes::new(),
+            proxy_creation_code: Bytes::new(),
+        }
+    }
+
+    #[test]
+    fn test_account_salt_recipes() {
+        let owner = address!("2222222222222222222222222222222222222222");
+        let salt = B256::with_last_byte(5);
+
+        let light = account(AccountPreset::Light, vec![owner]);
+        let mut packed = owner.to_vec();
+        packed.extend_from_slice(salt.as_slice());
+        assert_eq!(light.salt_recipe().apply(&salt), keccak256(packed));
+
+        // abi.encode(bytes[] owners, uint256 nonce) with the nonce in place
+        let coinbase = account(AccountPreset::Coinbase, vec![owner, Address::ZERO]);
+        let encoded =
+            <(sol_data::Array<sol_data::Bytes>, sol_data::Uint<256>)>::abi_encode_params(&(
+                vec![
+                    Bytes::from(owner.into_word().to_vec()),
+                    Bytes::from(B256::ZERO.to_vec()),
+                ],
+                U256::from(5),
+            ));
+        assert_eq!(coinbase.salt_recipe().apply(&salt), keccak256(encoded));
+
+        let mut kernel = account(AccountPreset::Kernel, vec![]);
+        assert!(kernel.validate().is_err());
+        kernel.init_call = bytes!("3c3b752b");
+        kernel.validate().unwrap();
+        assert_eq!(
+            kernel.salt_recipe().apply(&salt),
+            keccak256([&[0x3c, 0x3b, 0x75, 0x2b], salt.as_slice()].concat())
+        );
+    }
+
+    #[test]
+    fn test_simple_account_init_code() {
+        let owner = address!("2222222222222222222222222222222222222222");
+        let mut simple = account(AccountPreset::Simple, vec![owner]);
+        assert!(simple.validate().is_err());
+        simple.proxy_creation_code = bytes!("60806040");
+        simple.validate().unwrap();
+
+        let init_code = simple.init_code();
+        assert_eq!(&init_code[..4], &[0x60, 0x80, 0x60, 0x40]);
+        assert_eq!(&init_code[16..36], simple.implementation.as_slice());
+        // initialize(address) follows the bytes offset and length words
+        assert_eq!(&init_code[100..104], &[0xc4, 0xd6, 0x6d, 0xe8]);
+    }
+
+    fn assert_deriver_matches(init_code: &[u8], salt_recipe: SaltRecipe) {
+        let config = MinerConfig {
+            factory_address: address!("0ba5ed0c6aa8c49038f819e587e2633c4a9f428a"),
+            url_or_bytecode_bytes: keccak256(init_code).to_vec(),
+            pattern: AddressPattern::prefix("ab", false).unwrap(),
+            mode: MiningMode::Create2,
+            case_sensitive: false,
+            postfix_only: false,
+            dual_matching: false,
+            salt_template: SaltTemplate::default(),
+            salt_recipe,
+            proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
+        };
+
+        let salts: Vec<B256> = (0..20u64).map(|i| B256::from(U256::from(i))).collect();
+        let mut final_salts = vec![B256::ZERO; salts.len()];
+        let mut addresses = vec![Address::ZERO; salts.len()];
+        AddressDeriver::new(&config).derive_batch(&salts, &mut final_salts, &mut addresses);
+
+        for (i, salt) in salts.iter().enumerate() {
+            let final_salt = config.compute_final_salt(salt);
+            assert_eq!(final_salts[i], final_salt);
+            assert_eq!(addresses[i], get_create2_address(&config, &final_salt));
+        }
+    }
+
+    #[test]
+    fn test_coinbase_deriver() {
+        // Even one owner leaves 128 bytes after the nonce, past the keccak rate, so this
+        // covers the scalar fallback
+        let owners = vec![address!("2222222222222222222222222222222222222222"); 3];
+        let coinbase = account(AccountPreset::Coinbase, owners);
+        assert_deriver_matches(&coinbase.init_code(), coinbase.salt_recipe());
+
+        // keccak256(abi.encode(salt, owner, index)): a 64-byte tail fits one block, so full
+        // groups of salts go through the lane path with the tail copied after each salt
+        let owner = address!("2222222222222222222222222222222222222222");
+        let recipe = SaltRecipe::Hashed {
+            prefix: Vec::new(),
+            suffix: [owner.into_word(), B256::with_last_byte(1)].concat(),
+        };
+        assert_deriver_matches(&coinbase.init_code(), recipe);
+    }
+}
@@ -1,158 +1,408 @@
-use crate::config::{MinerConfig, MiningMode};
-use alloy::primitives::{keccak256, Address, B256};
-
-// Solady's CREATE3 proxy init code hash constant
-const CREATE3_PROXY_INITCODE_HASH: B256 = B256::new([
+use crate::config::{MinerConfig, MiningMode, SaltRecipe};
+use crate::init_code::InitCodeVariation;
+use crate::keccak::{KeccakBackend, KeccakMidstate, MAX_LANES, RATE};
+use alloy::primitives::{b256, keccak256, Address, Keccak256, B256};
+
+/// Init code hash of the CREATE3 proxy shared by Solady, solmate, 0xSequence, ZeframLou
+/// and CreateX
+pub const CREATE3_PROXY_INITCODE_HASH: B256 = B256::new([
     0x21, 0xc3, 0x5d, 0xbe, 0x1b, 0x34, 0x4a, 0x24, 0x88, 0xcf, 0x33, 0x21, 0xd6, 0xce, 0x54, 0x2f,
     0x8e, 0x9f, 0x30, 0x55, 0x44, 0xff, 0x09, 0xe4, 0x99, 0x3a, 0x62, 0x31, 0x9a, 0x49, 0x7c, 0x1f,
 ]);
 
 pub fn get_create2_address(config: &MinerConfig, salt: &B256) -> Address {
     // CREATE2 address = keccak256(0xff + factory + salt + keccak256(bytecode))[12:]
-    let mut packed = Vec::with_capacity(1 + 20 + 32 + config.url_or_bytecode_bytes.len());
-    packed.push(0xff);
-    packed.extend_from_slice(config.factory_address.as_slice());
-    packed.extend_from_slice(salt.as_slice());
-    packed.extend_from_slice(&config.url_or_bytecode_bytes);
-
-    let hash = keccak256(&packed);
+    let hash = keccak256(create2_input(
+        &config.factory_address,
+        salt,
+        &B256::from_slice(&config.url_or_bytecode_bytes),
+    ));
     Address::from_slice(&hash[12..])
 }
 
+/// CREATE2 address of the CREATE3 proxy for a final salt
+pub fn get_create3_proxy_address(config: &MinerConfig, salt: &B256) -> Address {
+    let proxy_hash = keccak256(create2_input(
+        &config.factory_address,
+        salt,
+        &config.proxy_init_code_hash,
+    ));
+    Address::from_slice(&proxy_hash[12..])
+}
+
 pub fn get_create3_address(config: &MinerConfig, salt: &B256) -> Address {
     // Step 1: Compute proxy address using CREATE2
-    let mut packed = Vec::with_capacity(85);
-    packed.push(0xff);
-    packed.extend_from_slice(config.factory_address.as_slice());
-    packed.extend_from_slice(salt.as_slice());
-    packed.extend_from_slice(CREATE3_PROXY_INITCODE_HASH.as_slice());
-
-    let proxy_hash = keccak256(&packed);
-    let proxy_addr = Address::from_slice(&proxy_hash[12..]);
+    let proxy = get_create3_proxy_address(config, salt);
 
     // Step 2: Compute deployed address using CREATE from proxy (nonce=1)
-    // Manual RLP encoding for [address, nonce=1]
-    let mut rlp_data = Vec::with_capacity(23);
-    rlp_data.push(0xd6); // RLP list with 22 bytes (0xc0 + 22)
-    rlp_data.push(0x94); // Byte string with 20 bytes (0x80 + 20)
-    rlp_data.extend_from_slice(proxy_addr.as_slice());
-    rlp_data.push(0x01); // Integer 1
-
-    let deployed_hash = keccak256(&rlp_data);
-    Address::from_slice(&deployed_hash[12..])
+    proxy_deployed_address(proxy.as_slice())
+}
+
+/// CREATE2 address for a varied init code: `init_code_hash` with the variation's fixed salt
+pub fn get_init_code_variation_address(
+    config: &MinerConfig,
+    variation: &InitCodeVariation,
+    init_code_hash: &B256,
+) -> Address {
+    let hash = keccak256(create2_input(
+        &config.factory_address,
+        &variation.salt,
+        init_code_hash,
+    ));
+    Address::from_slice(&hash[12..])
+}
+
+/// keccak256("zksyncCreate2"), the zkSync Era CREATE2 domain prefix
+pub const ZKSYNC_CREATE2_PREFIX: B256 =
+    b256!("2020dba91b30cc0006188af794c2fb30dd8520db7e2c088b7fc7c103c00ca494");
+
+/// keccak256("zksyncCreate"), the zkSync Era CREATE domain prefix
+pub const ZKSYNC_CREATE_PREFIX: B256 =
+    b256!("63bae3a9951d38e8a3fbb7b70909afc1200610fc5bc55ade242f815974674f23");
+
+/// zkSync Era CREATE2 address:
+/// keccak256(keccak256("zksyncCreate2") ++ sender ++ salt ++ bytecodeHash ++ keccak256(input))[12:]
+pub fn get_zksync_create2_address(
+    config: &MinerConfig,
+    salt: &B256,
+    constructor_input_hash: &B256,
+) -> Address {
+    let hash = keccak256(zksync_create2_input(
+        &config.factory_address,
+        salt,
+        &B256::from_slice(&config.url_or_bytecode_bytes),
+        constructor_input_hash,
+    ));
+    Address::from_slice(&hash[12..])
+}
+
+/// zkSync Era CREATE address, derived from the deployment nonce rather than RLP:
+/// keccak256(keccak256("zksyncCreate") ++ sender ++ nonce)[12:]
+pub fn get_zksync_create_address(deployer: &Address, nonce: u64) -> Address {
+    let mut input = [0u8; 96];
+    input[..32].copy_from_slice(ZKSYNC_CREATE_PREFIX.as_slice());
+    input[44..64].copy_from_slice(deployer.as_slice());
+    input[88..].copy_from_slice(&nonce.to_be_bytes());
+    Address::from_slice(&keccak256(input)[12..])
 }
 
 pub fn get_deployed_address(config: &MinerConfig, salt: &B256) -> Address {
-    match config.mode {
+    match &config.mode {
         MiningMode::Create2 => get_create2_address(config, salt),
         MiningMode::Create3 => get_create3_address(config, salt),
+        MiningMode::InitCode(variation) => get_init_code_variation_address(config, variation, salt),
+        MiningMode::ZksyncCreate2 {
+            constructor_input_hash,
+        } => get_zksync_create2_address(config, salt, constructor_input_hash),
     }
 }
 
-/// Check if address matches the target pattern
-pub fn check_address_match(addr: &Address, config: &MinerConfig) -> bool {
-    if config.case_sensitive {
-        // Use EIP-55 checksum format for case-sensitive matching
-        let checksum_addr = addr.to_checksum(None);
-
-        let prefix_target = String::from_utf8_lossy(&config.prefix_bytes).to_string();
-        let postfix_target = String::from_utf8_lossy(&config.postfix_bytes).to_string();
-
-        if config.dual_matching {
-            // Both prefix and postfix must match
-            checksum_addr.starts_with(&prefix_target) && checksum_addr.ends_with(&postfix_target)
-        } else if config.postfix_only {
-            // Legacy postfix-only mode
-            checksum_addr.ends_with(&prefix_target)
-        } else {
-            // Prefix-only mode
-            checksum_addr.starts_with(&prefix_target)
+/// Longest address preimage: the zkSync CREATE2 one
+const MAX_PREIMAGE_LEN: usize = 160;
+
+/// Build the 85-byte CREATE2 preimage `0xff ++ factory ++ salt ++ init_code_hash` on the stack
+fn create2_input(factory: &Address, salt: &B256, init_code_hash: &B256) -> [u8; 85] {
+    let mut input = [0u8; 85];
+    input[0] = 0xff;
+    input[1..21].copy_from_slice(factory.as_slice());
+    input[21..53].copy_from_slice(salt.as_slice());
+    input[53..85].copy_from_slice(init_code_hash.as_slice());
+    input
+}
+
+/// Build the 160-byte zkSync CREATE2 preimage, with the sender left-padded to 32 bytes
+fn zksync_create2_input(
+    sender: &Address,
+    salt: &B256,
+    bytecode_hash: &B256,
+    constructor_input_hash: &B256,
+) -> [u8; MAX_PREIMAGE_LEN] {
+    let mut input = [0u8; MAX_PREIMAGE_LEN];
+    input[..32].copy_from_slice(ZKSYNC_CREATE2_PREFIX.as_slice());
+    input[44..64].copy_from_slice(sender.as_slice());
+    input[64..96].copy_from_slice(salt.as_slice());
+    input[96..128].copy_from_slice(bytecode_hash.as_slice());
+    input[128..].copy_from_slice(constructor_input_hash.as_slice());
+    input
+}
+
+/// Address of the contract deployed by `proxy` with CREATE at nonce 1
+fn proxy_deployed_address(proxy: &[u8]) -> Address {
+    get_create_address(&Address::from_slice(proxy), 1)
+}
+
+/// CREATE address: keccak256(rlp([deployer, nonce]))[12:]
+pub fn get_create_address(deployer: &Address, nonce: u64) -> Address {
+    // Manual RLP encoding: the list is at most 1 + 21 + 9 bytes, always a short list
+    let mut rlp_data = [0u8; 31];
+    rlp_data[1] = 0x94; // Byte string with 20 bytes (0x80 + 20)
+    rlp_data[2..22].copy_from_slice(deployer.as_slice());
+
+    let nonce_len = match nonce {
+        0 => {
+            rlp_data[22] = 0x80; // Empty string
+            1
         }
-    } else {
-        // Use fast case-insensitive matching
-        if config.dual_matching {
-            // Both prefix and postfix must match
-            check_prefix_match(addr, &config.prefix_bytes, config.prefix_len)
-                && check_postfix_match(addr, &config.postfix_bytes, config.postfix_len)
-        } else if config.postfix_only {
-            // Legacy postfix-only mode
-            check_postfix_match(addr, &config.prefix_bytes, config.prefix_len)
-        } else {
-            // Prefix-only mode
-            check_prefix_match(addr, &config.prefix_bytes, config.prefix_len)
+        1..=0x7f => {
+            rlp_data[22] = nonce as u8; // Single byte encodes itself
+            1
         }
-    }
+        _ => {
+            let bytes = nonce.to_be_bytes();
+            let significant = 8 - nonce.leading_zeros() as usize / 8;
+            rlp_data[22] = 0x80 + significant as u8;
+            rlp_data[23..23 + significant].copy_from_slice(&bytes[8 - significant..]);
+            1 + significant
+        }
+    };
+    let payload_len = 21 + nonce_len;
+    rlp_data[0] = 0xc0 + payload_len as u8; // RLP list header
+
+    let deployed_hash = keccak256(&rlp_data[..1 + payload_len]);
+    Address::from_slice(&deployed_hash[12..])
 }
 
-fn check_prefix_match(addr: &Address, prefix: &[u8], prefix_len: usize) -> bool {
-    // Convert to hex manually for better performance
-    const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
-    let addr_bytes = addr.as_slice();
+/// Derivation context prepared once per [`MinerConfig`].
+///
+/// Everything constant across salts is filled in up front: the address preimage only
+/// needs its final salt slot overwritten, and whatever is hashed around the raw salt (a
+/// salt recipe prefix such as the CREATE3 URL, or the init code around a mined counter) is
+/// already absorbed into a keccak state or kept as a fixed tail. Deriving an address
+/// therefore never touches the heap.
+///
+/// [`Self::derive_batch`] additionally hashes several salts at once on the widest
+/// [`KeccakBackend`] the CPU supports.
+#[derive(Clone)]
+pub struct AddressDeriver {
+    /// Whether the CREATE2 address is a CREATE3 proxy
+    create3: bool,
+    /// CREATE2 preimage (`0xff ++ factory ++ salt ++ init_code_hash` or the zkSync one)
+    /// with a zeroed final salt slot
+    preimage: [u8; MAX_PREIMAGE_LEN],
+    preimage_len: usize,
+    /// Offset of the final salt in `preimage`: the salt, or the init code hash
+    final_slot: usize,
+    /// Keccak midstate with the preimage before the final salt absorbed
+    preimage_midstate: KeccakMidstate,
+    /// Whether the raw salt is hashed into the final salt
+    hash_salt: bool,
+    /// First byte of the raw salt that is hashed
+    salt_start: usize,
+    /// Bytes hashed after the raw salt
+    salt_tail: Vec<u8>,
+    /// Keccak midstate with everything before the raw salt absorbed
+    salt_hasher: Keccak256,
+    /// Same midstate for the lane-parallel backend
+    salt_midstate: KeccakMidstate,
+    backend: KeccakBackend,
+}
 
-    for (i, &prefix_char) in prefix.iter().enumerate().take(prefix_len) {
-        let byte_idx = i / 2;
-        let is_high_nibble = i % 2 == 0;
+impl AddressDeriver {
+    pub fn new(config: &MinerConfig) -> Self {
+        let create2 = |salt: &B256, init_code_hash: &B256| {
+            let mut preimage = [0u8; MAX_PREIMAGE_LEN];
+            preimage[..85].copy_from_slice(&create2_input(
+                &config.factory_address,
+                salt,
+                init_code_hash,
+            ));
+            (preimage, 85)
+        };
+        let ((preimage, preimage_len), final_slot) = match &config.mode {
+            MiningMode::Create2 => (
+                create2(
+                    &B256::ZERO,
+                    &B256::from_slice(&config.url_or_bytecode_bytes),
+                ),
+                21,
+            ),
+            MiningMode::Create3 => (create2(&B256::ZERO, &config.proxy_init_code_hash), 21),
+            MiningMode::InitCode(variation) => (create2(&variation.salt, &B256::ZERO), 53),
+            MiningMode::ZksyncCreate2 {
+                constructor_input_hash,
+            } => (
+                (
+                    zksync_create2_input(
+                        &config.factory_address,
+                        &B256::ZERO,
+                        &B256::from_slice(&config.url_or_bytecode_bytes),
+                        constructor_input_hash,
+                    ),
+                    MAX_PREIMAGE_LEN,
+                ),
+                64,
+            ),
+        };
+        let mut preimage_midstate = KeccakMidstate::new();
+        preimage_midstate.update(&preimage[..final_slot]);
+
+        let (hash_salt, prefix, salt_start, salt_tail) = match (&config.mode, &config.salt_recipe) {
+            (MiningMode::InitCode(variation), _) => (
+                true,
+                variation.prefix(),
+                32 - variation.len,
+                variation.tail().to_vec(),
+            ),
+            (_, SaltRecipe::Hashed { prefix, suffix }) => (true, &prefix[..], 0, suffix.clone()),
+            (_, SaltRecipe::Raw) => (false, &[][..], 0, Vec::new()),
+        };
 
-        if byte_idx >= addr_bytes.len() {
-            return false;
+        let mut salt_hasher = Keccak256::new();
+        let mut salt_midstate = KeccakMidstate::new();
+        salt_hasher.update(prefix);
+        salt_midstate.update(prefix);
+
+        Self {
+            create3: matches!(config.mode, MiningMode::Create3),
+            preimage,
+            preimage_len,
+            final_slot,
+            preimage_midstate,
+            hash_salt,
+            salt_start,
+            salt_tail,
+            salt_hasher,
+            salt_midstate,
+            backend: KeccakBackend::detect(),
         }
+    }
 
-        let nibble = if is_high_nibble {
-            (addr_bytes[byte_idx] >> 4) & 0x0f
-        } else {
-            addr_bytes[byte_idx] & 0x0f
-        };
+    /// Use a specific keccak backend for [`Self::derive_batch`]
+    pub fn with_backend(mut self, backend: KeccakBackend) -> Self {
+        self.backend = backend;
+        self
+    }
 
-        let addr_char = HEX_CHARS[nibble as usize];
-        let prefix_char_lower = prefix_char.to_ascii_lowercase();
+    pub fn backend(&self) -> KeccakBackend {
+        self.backend
+    }
 
-        if addr_char != prefix_char_lower {
-            return false;
+    /// Same result as [`MinerConfig::compute_final_salt`]
+    #[inline]
+    pub fn final_salt(&self, salt: &B256) -> B256 {
+        if self.hash_salt {
+            let mut hasher = self.salt_hasher.clone();
+            hasher.update(&salt[self.salt_start..]);
+            hasher.update(&self.salt_tail);
+            hasher.finalize()
+        } else {
+            *salt
         }
     }
-    true
-}
 
-fn check_postfix_match(addr: &Address, postfix: &[u8], postfix_len: usize) -> bool {
-    // Convert to hex manually for better performance
-    const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
-    let addr_bytes = addr.as_slice();
+    /// Same result as [`get_deployed_address`] for an already finalized salt
+    #[inline]
+    pub fn deployed_address(&self, final_salt: &B256) -> Address {
+        let mut input = self.preimage;
+        input[self.final_slot..self.final_slot + 32].copy_from_slice(final_salt.as_slice());
+        let hash = keccak256(&input[..self.preimage_len]);
 
-    // Address is 20 bytes = 40 hex chars
-    if postfix_len > 40 {
-        return false;
+        if self.create3 {
+            proxy_deployed_address(&hash[12..])
+        } else {
+            Address::from_slice(&hash[12..])
+        }
     }
 
-    let start_pos = 40 - postfix_len;
-
-    for (i, &postfix_char) in postfix.iter().enumerate().take(postfix_len) {
-        let hex_pos = start_pos + i;
-        let byte_idx = hex_pos / 2;
-        let is_high_nibble = hex_pos % 2 == 0;
+    /// Derive final salts and deployed addresses for a slice of raw salts.
+    ///
+    /// Results are identical to calling [`Self::final_salt`] and [`Self::deployed_address`]
+    /// per salt; full groups of [`KeccakBackend::lanes`] salts are hashed in parallel and
+    /// any remainder goes through the scalar path.
+    pub fn derive_batch(
+        &self,
+        salts: &[B256],
+        final_salts: &mut [B256],
+        addresses: &mut [Address],
+    ) {
+        assert!(final_salts.len() == salts.len() && addresses.len() == salts.len());
+
+        let lanes = self.backend.lanes();
+        let suffix_len = 32 - self.salt_start + self.salt_tail.len();
+        let mut i = 0;
+        if lanes > 1 && suffix_len <= RATE {
+            while i + lanes <= salts.len() {
+                self.derive_lanes(
+                    &salts[i..i + lanes],
+                    &mut final_salts[i..i + lanes],
+                    &mut addresses[i..i + lanes],
+                );
+                i += lanes;
+            }
+        }
 
-        if byte_idx >= addr_bytes.len() {
-            return false;
+        for j in i..salts.len() {
+            final_salts[j] = self.final_salt(&salts[j]);
+            addresses[j] = self.deployed_address(&final_salts[j]);
         }
+    }
 
-        let nibble = if is_high_nibble {
-            (addr_bytes[byte_idx] >> 4) & 0x0f
+    fn derive_lanes(&self, salts: &[B256], final_salts: &mut [B256], addresses: &mut [Address]) {
+        const EMPTY: KeccakMidstate = KeccakMidstate::new();
+        let lanes = salts.len();
+        let preimage_tail = self.preimage_len - self.final_slot;
+        let mut hashes = [B256::ZERO; MAX_LANES];
+
+        if self.hash_salt {
+            let salt_len = 32 - self.salt_start;
+            let suffix_len = salt_len + self.salt_tail.len();
+            let mut buffers = [[0u8; RATE]; MAX_LANES];
+            for (buffer, salt) in buffers.iter_mut().zip(salts) {
+                buffer[..salt_len].copy_from_slice(&salt[self.salt_start..]);
+                buffer[salt_len..suffix_len].copy_from_slice(&self.salt_tail);
+            }
+            let suffixes: [&[u8]; MAX_LANES] = std::array::from_fn(|l| &buffers[l][..suffix_len]);
+            self.backend
+                .finalize_lanes(&self.salt_midstate, &suffixes[..lanes], final_salts);
         } else {
-            addr_bytes[byte_idx] & 0x0f
-        };
+            final_salts.copy_from_slice(salts);
+        }
 
-        let addr_char = HEX_CHARS[nibble as usize];
-        let postfix_char_lower = postfix_char.to_ascii_lowercase();
+        // Only the preimage from the final salt on differs per lane
+        let mut inputs = [[0u8; MAX_PREIMAGE_LEN]; MAX_LANES];
+        for (input, final_salt) in inputs.iter_mut().zip(final_salts.iter()) {
+            input[..preimage_tail]
+                .copy_from_slice(&self.preimage[self.final_slot..self.preimage_len]);
+            input[..32].copy_from_slice(final_salt.as_slice());
+        }
+        let suffixes: [&[u8]; MAX_LANES] = std::array::from_fn(|l| &inputs[l][..preimage_tail]);
+        self.backend.finalize_lanes(
+            &self.preimage_midstate,
+            &suffixes[..lanes],
+            &mut hashes[..lanes],
+        );
+
+        if self.create3 {
+            // CREATE from the proxy at nonce 1: rlp([proxy, 1])
+            let mut rlp_inputs = [[0u8; 23]; MAX_LANES];
+            for (rlp_data, proxy_hash) in rlp_inputs.iter_mut().zip(&hashes[..lanes]) {
+                rlp_data[0] = 0xd6;
+                rlp_data[1] = 0x94;
+                rlp_data[2..22].copy_from_slice(&proxy_hash[12..]);
+                rlp_data[22] = 0x01;
+            }
+            let suffixes: [&[u8]; MAX_LANES] = std::array::from_fn(|l| &rlp_inputs[l][..]);
+            self.backend
+                .finalize_lanes(&EMPTY, &suffixes[..lanes], &mut hashes[..lanes]);
+        }
 
-        if addr_char != postfix_char_lower {
-            return false;
+        for (address, hash) in addresses.iter_mut().zip(&hashes[..lanes]) {
+            *address = Address::from_slice(&hash[12..]);
         }
     }
-    true
+}
+
+/// Check if address matches the target pattern
+#[inline]
+pub fn check_address_match(addr: &Address, config: &MinerConfig) -> bool {
+    config.pattern.matches(addr)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::pattern::AddressPattern;
+    use crate::salt::SaltTemplate;
     use alloy::primitives::{address, b256};
 
     fn create_test_config_create2() -> MinerConfig {
@@ -162,32 +412,59 @@ mod tests {
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
             )
             .to_vec(),
-            prefix_bytes: b"dead".to_vec(),
-            prefix_len: 4,
-            postfix_bytes: Vec::new(),
-            postfix_len: 0,
+            pattern: AddressPattern::prefix("dead", false).unwrap(),
             mode: MiningMode::Create2,
             case_sensitive: false,
             postfix_only: false,
             dual_matching: false,
+            salt_template: SaltTemplate::default(),
+            salt_recipe: SaltRecipe::Raw,
+            proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
         }
     }
 
     fn create_test_config_create3() -> MinerConfig {
         MinerConfig {
             factory_address: address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8"),
-            url_or_bytecode_bytes: b"https://example.com".to_vec(),
-            prefix_bytes: b"cafe".to_vec(),
-            prefix_len: 4,
-            postfix_bytes: Vec::new(),
-            postfix_len: 0,
+            url_or_bytecode_bytes: Vec::new(),
+            pattern: AddressPattern::prefix("cafe", false).unwrap(),
             mode: MiningMode::Create3,
             case_sensitive: false,
             postfix_only: false,
             dual_matching: false,
+            salt_template: SaltTemplate::default(),
+            salt_recipe: SaltRecipe::url(b"https://example.com"),
+            proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
         }
     }
 
+    /// Solady-style CREATE3 with the salt used as-is and a non-default proxy hash
+    fn create_test_config_create3_raw() -> MinerConfig {
+        let mut config = create_test_config_create3();
+        config.salt_recipe = SaltRecipe::Raw;
+        config.proxy_init_code_hash = B256::repeat_byte(0x42);
+        config
+    }
+
+    /// CREATE2 with a fixed salt, mining `range` of the init code `0, 1, 2, ...`
+    fn create_test_config_init_code(init_code_len: u8, range: &str) -> MinerConfig {
+        let mut config = create_test_config_create2();
+        let init_code = (0..init_code_len).collect();
+        let salt = b256!("00000000000000000000000000000000000000000000000000000000000000aa");
+        config.mode = MiningMode::InitCode(InitCodeVariation::new(init_code, range, salt).unwrap());
+        config
+    }
+
+    fn create_test_config_zksync() -> MinerConfig {
+        let mut config = create_test_config_create2();
+        config.url_or_bytecode_bytes =
+            b256!("010000358b7c5d2ac6d3f2e2ae5c8d3b2dcbc1c49f6b2b23ff8f9b9e79bd5b4e").to_vec();
+        config.mode = MiningMode::ZksyncCreate2 {
+            constructor_input_hash: keccak256([0xab; 64]),
+        };
+        config
+    }
+
     #[test]
     fn test_get_create2_address() {
         let config = create_test_config_create2();
@@ -248,42 +525,201 @@ mod tests {
     }
 
     #[test]
-    fn test_check_prefix_match() {
-        // Create an address that starts with 'dead'
-        let addr = address!("deadbeefcafebabe1234567890abcdef12345678");
+    fn test_address_deriver_matches_reference() {
+        let mut long_url_config = create_test_config_create3();
+        // Longer than one keccak block, so the midstate spans a permutation
+        long_url_config.salt_recipe = SaltRecipe::url(&b"https://example.com/".repeat(10));
+
+        for config in [
+            create_test_config_create2(),
+            create_test_config_create3(),
+            long_url_config,
+            create_test_config_create3_raw(),
+            create_test_config_init_code(50, "-40:32"),
+            // Prefix spanning a keccak block
+            create_test_config_init_code(200, "-40:32"),
+            // Counter and tail longer than a keccak block
+            create_test_config_init_code(200, "10:8"),
+        ] {
+            let deriver = AddressDeriver::new(&config);
+            for i in [0u64, 1, 42, 1 << 40, u64::MAX] {
+                let salt = crate::utils::to_bytes32(i);
+                let final_salt = config.compute_final_salt(&salt);
+                assert_eq!(deriver.final_salt(&salt), final_salt);
+                assert_eq!(
+                    deriver.deployed_address(&final_salt),
+                    get_deployed_address(&config, &final_salt)
+                );
+            }
+        }
+    }
 
-        let prefix = b"dead";
-        assert!(check_prefix_match(&addr, prefix, 4));
+    #[test]
+    fn test_derive_batch_matches_scalar_on_all_backends() {
+        let salts: Vec<B256> = (0..37u64).map(crate::utils::to_bytes32).collect();
+
+        for config in [
+            create_test_config_create2(),
+            create_test_config_create3(),
+            create_test_config_create3_raw(),
+            create_test_config_init_code(100, "-40:32"),
+            create_test_config_init_code(250, "-3:3"),
+            create_test_config_init_code(250, "0:32"),
+            create_test_config_zksync(),
+        ] {
+            for backend in KeccakBackend::available() {
+                let deriver = AddressDeriver::new(&config).with_backend(backend);
+                let mut final_salts = vec![B256::ZERO; salts.len()];
+                let mut addresses = vec![Address::ZERO; salts.len()];
+                deriver.derive_batch(&salts, &mut final_salts, &mut addresses);
+
+                for (i, salt) in salts.iter().enumerate() {
+                    let final_salt = config.compute_final_salt(salt);
+                    assert_eq!(final_salts[i], final_salt, "{:?}", backend);
+                    assert_eq!(
+                        addresses[i],
+                        get_deployed_address(&config, &final_salt),
+                        "{:?}",
+                        backend
+                    );
+                }
+            }
+        }
+    }
 
-        let wrong_prefix = b"cafe";
-        assert!(!check_prefix_match(&addr, wrong_prefix, 4));
+    #[test]
+    fn test_create2_and_create3_match_alloy() {
+        let config = create_test_config_create2();
+        let salt = crate::utils::to_bytes32(7);
+        let hash = B256::from_slice(&config.url_or_bytecode_bytes);
+        assert_eq!(
+            get_create2_address(&config, &salt),
+            config.factory_address.create2(salt, hash)
+        );
 
-        // Test partial match
-        let partial_prefix = b"de";
-        assert!(check_prefix_match(&addr, partial_prefix, 2));
+        let config = create_test_config_create3();
+        let proxy = config
+            .factory_address
+            .create2(salt, CREATE3_PROXY_INITCODE_HASH);
+        assert_eq!(get_create3_address(&config, &salt), proxy.create(1));
+
+        let config = create_test_config_create3_raw();
+        let proxy = config
+            .factory_address
+            .create2(salt, config.proxy_init_code_hash);
+        assert_eq!(get_create3_proxy_address(&config, &salt), proxy);
+        assert_eq!(get_create3_address(&config, &salt), proxy.create(1));
     }
 
     #[test]
-    fn test_check_postfix_match() {
-        // Create an address that ends with 'beef'
-        let addr = address!("1234567890abcdef1234567890abcdefdeadbeef");
+    fn test_init_code_variation_matches_alloy() {
+        let config = create_test_config_init_code(100, "-40:32");
+        let MiningMode::InitCode(variation) = &config.mode else {
+            unreachable!()
+        };
+        let counter = crate::utils::to_bytes32(12345);
+        let init_code_hash = config.compute_final_salt(&counter);
+        assert_eq!(init_code_hash, keccak256(variation.init_code_at(&counter)));
+        assert_eq!(
+            get_deployed_address(&config, &init_code_hash),
+            config
+                .factory_address
+                .create2(variation.salt, init_code_hash)
+        );
+    }
 
-        let postfix = b"beef";
-        assert!(check_postfix_match(&addr, postfix, 4));
+    #[test]
+    fn test_create_address_matches_alloy_for_all_nonce_sizes() {
+        let deployer = address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8");
+        for nonce in [
+            0,
+            1,
+            0x7f,
+            0x80,
+            0xff,
+            0x100,
+            0xffff,
+            0x1_0000,
+            1 << 40,
+            u64::MAX - 1,
+            u64::MAX,
+        ] {
+            assert_eq!(
+                get_create_address(&deployer, nonce),
+                deployer.create(nonce),
+                "nonce {}",
+                nonce
+            );
+        }
+    }
 
-        let wrong_postfix = b"cafe";
-        assert!(!check_postfix_match(&addr, wrong_postfix, 4));
+    /// Config whose pattern is compiled from CLI-style options
+    fn config_with_pattern(
+        prefix: &str,
+        postfix: Option<&str>,
+        postfix_only: bool,
+        case_sensitive: bool,
+    ) -> MinerConfig {
+        let mut config = create_test_config_create2();
+        config.pattern =
+            AddressPattern::from_args(prefix, postfix, postfix_only, case_sensitive).unwrap();
+        config.case_sensitive = case_sensitive;
+        config.postfix_only = postfix_only;
+        config.dual_matching = postfix.is_some();
+        config
+    }
 
-        // Test partial match
-        let partial_postfix = b"ef";
-        assert!(check_postfix_match(&addr, partial_postfix, 2));
+    #[test]
+    fn test_zksync_addresses() {
+        assert_eq!(ZKSYNC_CREATE2_PREFIX, keccak256("zksyncCreate2"));
+        assert_eq!(ZKSYNC_CREATE_PREFIX, keccak256("zksyncCreate"));
+
+        // zksync-ethers `utils.createAddress` / `utils.create2Address` reference vectors
+        let sender = address!("36615cf349d7f6344891b1e7ca7c72883f5dc049");
+        assert_eq!(
+            get_zksync_create_address(&sender, 1),
+            address!("4b5df730c2e6b28e17013a1485e5d9bc41efe021")
+        );
+        // create2Address(sender, bytecodeHash, "0x01", "0x01") hashes its 1-byte salt
+        // unpadded, so the vector only pins the prefix and field order of the preimage
+        let bytecode_hash =
+            b256!("010001cb6a6e8d5f6829522f19fa9568660e0a9cd53b2e8be4deb0a679452e41");
+        let mut preimage =
+            zksync_create2_input(&sender, &B256::ZERO, &bytecode_hash, &keccak256([0x01])).to_vec();
+        preimage.splice(64..96, [0x01]);
+        assert_eq!(
+            Address::from_slice(&keccak256(&preimage)[12..]),
+            address!("29bac3e5e8ffe7415f97c956bfa106d70316ad50")
+        );
+
+        // Full-width salts go through the same preimage on every derivation path
+        let config = create_test_config_zksync();
+        let salt = b256!("00000000000000000000000000000000000000000000000000000000000000aa");
+        let MiningMode::ZksyncCreate2 {
+            constructor_input_hash,
+        } = config.mode
+        else {
+            unreachable!()
+        };
+        let expected = Address::from_slice(
+            &keccak256(zksync_create2_input(
+                &config.factory_address,
+                &salt,
+                &B256::from_slice(&config.url_or_bytecode_bytes),
+                &constructor_input_hash,
+            ))[12..],
+        );
+        assert_eq!(get_deployed_address(&config, &salt), expected);
+        assert_eq!(
+            AddressDeriver::new(&config).deployed_address(&salt),
+            expected
+        );
+        assert_ne!(get_create2_address(&config, &salt), expected);
     }
 
     #[test]
     fn test_check_address_match_prefix_only() {
-        let mut config = create_test_config_create2();
-        config.prefix_bytes = b"dead".to_vec();
-        config.prefix_len = 4;
+        let config = config_with_pattern("dead", None, false, false);
 
         let matching_addr = address!("deadbeefcafebabe1234567890abcdef12345678");
         let non_matching_addr = address!("cafebabedeadbeef1234567890abcdef12345678");
@@ -294,10 +730,7 @@ mod tests {
 
     #[test]
     fn test_check_address_match_postfix_only() {
-        let mut config = create_test_config_create2();
-        config.prefix_bytes = b"beef".to_vec();
-        config.prefix_len = 4;
-        config.postfix_only = true;
+        let config = config_with_pattern("beef", None, true, false);
 
         let matching_addr = address!("1234567890abcdef1234567890abcdefdeadbeef");
         let non_matching_addr = address!("beefcafebabe1234567890abcdef123456789012");
@@ -308,12 +741,7 @@ mod tests {
 
     #[test]
     fn test_check_address_match_dual_matching() {
-        let mut config = create_test_config_create2();
-        config.prefix_bytes = b"dead".to_vec();
-        config.prefix_len = 4;
-        config.postfix_bytes = b"beef".to_vec();
-        config.postfix_len = 4;
-        config.dual_matching = true;
+        let config = config_with_pattern("dead", Some("beef"), false, false);
 
         let matching_addr = address!("deadbeefcafebabe1234567890abcdefdeadbeef");
         let prefix_only_addr = address!("deadbeefcafebabe1234567890abcdef12345678");
@@ -328,27 +756,64 @@ mod tests {
 
     #[test]
     fn test_check_address_match_case_sensitive() {
-        let mut config = create_test_config_create2();
-        config.case_sensitive = true;
-        config.prefix_bytes = b"0xDead".to_vec(); // Note the capital D
-
-        // This test is more complex because EIP-55 checksum addresses depend on the full address
-        // We'll test that the case-sensitive flag changes behavior
-        let addr = address!("deadbeefcafebabe1234567890abcdef12345678");
-
-        // Test with case sensitive vs insensitive
-        config.case_sensitive = false;
-        config.prefix_bytes = b"dead".to_vec();
-        config.prefix_len = 4;
-        let case_insensitive_result = check_address_match(&addr, &config);
-
-        config.case_sensitive = true;
-        let case_sensitive_result = check_address_match(&addr, &config);
-
-        // Results may differ based on EIP-55 checksum
-        // At minimum, the function should handle both modes without panicking
-        // The important thing is that both functions execute without panic
-        let _ = case_insensitive_result;
-        let _ = case_sensitive_result;
+        // EIP-55 reference vector: 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed
+        let addr = address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+        let matches = |prefix: &str, postfix: Option<&str>, postfix_only: bool| {
+            check_address_match(
+                &addr,
+                &config_with_pattern(prefix, postfix, postfix_only, true),
+            )
+        };
+
+        assert!(matches("5aAeb6053F", None, false));
+
+        // Correct nibbles with wrong casing must be rejected
+        assert!(!matches("5aaeb6053f", None, false));
+        assert!(!matches("5AAeb6053F", None, false));
+
+        // Postfix-only mode
+        assert!(matches("1BeAed", None, true));
+        assert!(!matches("1beaed", None, true));
+
+        // Dual mode
+        assert!(matches("5aAe", Some("BeAed"), false));
+        assert!(!matches("5aAe", Some("BEAed"), false));
+    }
+
+    #[test]
+    fn test_case_sensitive_mining_finds_checksum_matches() {
+        let cases = [
+            ("aB", None, false),     // prefix
+            ("Fe", None, true),      // postfix-only
+            ("D", Some("c"), false), // dual
+        ];
+
+        for (prefix, postfix, postfix_only) in cases {
+            let config = config_with_pattern(prefix, postfix, postfix_only, true);
+            let mut found = false;
+
+            for i in 0..50_000u64 {
+                let addr = get_create2_address(&config, &crate::utils::to_bytes32(i));
+                let checksum = addr.to_checksum(None);
+                let hex = &checksum[2..];
+
+                // Reference semantics: compare against the EIP-55 checksum string
+                let expected = match postfix {
+                    Some(postfix) => hex.starts_with(prefix) && hex.ends_with(postfix),
+                    None if postfix_only => hex.ends_with(prefix),
+                    None => hex.starts_with(prefix),
+                };
+
+                assert_eq!(
+                    check_address_match(&addr, &config),
+                    expected,
+                    "{}",
+                    checksum
+                );
+                found |= expected;
+            }
+
+            assert!(found, "no checksum match found for {:?}", prefix);
+        }
     }
 }
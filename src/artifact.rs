@@ -0,0 +1,273 @@
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::fmt;
+
+use crate::init_code::{parse_init_code_hex, InitCode};
+use crate::link::{link_bytecode, LibraryLink};
+
+/// Toolchain that produced a contract artifact
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArtifactFormat {
+    Foundry,
+    Hardhat,
+    Solc,
+    Vyper,
+    Huff,
+}
+
+impl fmt::Display for ArtifactFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Foundry => "Foundry",
+            Self::Hardhat => "Hardhat",
+            Self::Solc => "solc",
+            Self::Vyper => "Vyper",
+            Self::Huff => "Huff",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Creation bytecode and compiler metadata read from a build artifact
+#[derive(Clone, Debug)]
+pub struct Artifact {
+    pub format: ArtifactFormat,
+    pub contract: Option<String>,
+    /// Compiler version and settings, when the artifact records them
+    pub compiler: Option<String>,
+    pub init_code: InitCode,
+}
+
+impl Artifact {
+    pub fn load(path: &str, links: &[LibraryLink]) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read artifact '{}'", path))?;
+        let json: Value = serde_json::from_str(&text)
+            .with_context(|| format!("Artifact '{}' is not valid JSON", path))?;
+        Self::from_json(&json, path, links)
+    }
+
+    /// Extract the creation bytecode from Foundry, Hardhat, solc, Vyper or Huff JSON,
+    /// resolving library placeholders with `links`
+    pub fn from_json(json: &Value, source: &str, links: &[LibraryLink]) -> Result<Self> {
+        let (format, bytecode, contract) = if json["_format"]
+            .as_str()
+            .is_some_and(|f| f.starts_with("hh-sol-artifact"))
+        {
+            let contract = json["contractName"].as_str().map(str::to_string);
+            (ArtifactFormat::Hardhat, &json["bytecode"], contract)
+        } else if json["bytecode"]["object"].is_string() {
+            let contract = json["metadata"]["settings"]["compilationTarget"]
+                .as_object()
+                .and_then(|targets| targets.values().next())
+                .and_then(Value::as_str)
+                .map(str::to_string);
+            (
+                ArtifactFormat::Foundry,
+                &json["bytecode"]["object"],
+                contract,
+            )
+        } else if json["evm"]["bytecode"]["object"].is_string() {
+            (
+                ArtifactFormat::Solc,
+                &json["evm"]["bytecode"]["object"],
+                None,
+            )
+        } else if json["bytecode"].is_string() && json["runtime"].is_string() {
+            (ArtifactFormat::Huff, &json["bytecode"], None)
+        } else if json["bytecode"].is_string() {
+            (ArtifactFormat::Vyper, &json["bytecode"], None)
+        } else {
+            // Vyper combined_json: {"version": ..., "<path>": {"bytecode": ...}}
+            let mut contracts = json
+                .as_object()
+                .into_iter()
+                .flatten()
+                .filter(|(_, contract)| contract["bytecode"].is_string());
+            match (contracts.next(), contracts.next()) {
+                (Some((path, contract)), None) => (
+                    ArtifactFormat::Vyper,
+                    &contract["bytecode"],
+                    Some(path.clone()),
+                ),
+                (Some(_), Some(_)) => anyhow::bail!(
+                    "Artifact '{}' contains several contracts; export a single one",
+                    source
+                ),
+                (None, _) => anyhow::bail!(
+                    "No creation bytecode found in artifact '{}' (expected Foundry, Hardhat, solc, Vyper or Huff JSON)",
+                    source
+                ),
+            }
+        };
+
+        let bytecode = link_bytecode(
+            bytecode.as_str().unwrap_or_default(),
+            links,
+            &link_references(json),
+        )
+        .with_context(|| format!("Failed to link artifact '{}'", source))?;
+        if bytecode.trim_start_matches("0x").is_empty() {
+            anyhow::bail!(
+                "Artifact '{}' has no creation bytecode (abstract contract or interface?)",
+                source
+            );
+        }
+
+        Ok(Self {
+            format,
+            contract,
+            compiler: compiler_metadata(json, format),
+            init_code: InitCode {
+                bytes: parse_init_code_hex(&bytecode)?,
+                source: source.to_string(),
+                constructor_args: Vec::new(),
+            },
+        })
+    }
+}
+
+/// Fully qualified (`path:Lib`) libraries listed in the artifact's link references
+fn link_references(json: &Value) -> Vec<String> {
+    [
+        &json["linkReferences"],
+        &json["bytecode"]["linkReferences"],
+        &json["evm"]["bytecode"]["linkReferences"],
+    ]
+    .into_iter()
+    .filter_map(Value::as_object)
+    .flatten()
+    .flat_map(|(path, libraries)| {
+        libraries
+            .as_object()
+            .into_iter()
+            .flat_map(|libraries| libraries.keys())
+            .map(move |library| format!("{}:{}", path, library))
+    })
+    .collect()
+}
+
+/// Human-readable compiler version and optimizer settings
+fn compiler_metadata(json: &Value, format: ArtifactFormat) -> Option<String> {
+    let metadata = match &json["metadata"] {
+        // solc standard JSON stores metadata as a string
+        Value::String(raw) => serde_json::from_str(raw).ok()?,
+        metadata => metadata.clone(),
+    };
+
+    match format {
+        ArtifactFormat::Foundry | ArtifactFormat::Solc => {
+            let version = metadata["compiler"]["version"].as_str()?;
+            let language = metadata["language"].as_str().unwrap_or("Solidity");
+            let optimizer = &metadata["settings"]["optimizer"];
+            let mut description = format!("{} {}", language, version);
+            if optimizer["enabled"].as_bool() == Some(true) {
+                description += &format!(", optimizer {} runs", optimizer["runs"]);
+            }
+            if let Some(evm_version) = metadata["settings"]["evmVersion"].as_str() {
+                description += &format!(", evm {}", evm_version);
+            }
+            Some(description)
+        }
+        ArtifactFormat::Vyper => json["version"]
+            .as_str()
+            .map(|version| format!("Vyper {}", version)),
+        ArtifactFormat::Hardhat | ArtifactFormat::Huff => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::link::placeholder;
+    use serde_json::json;
+
+    #[test]
+    fn test_foundry_and_hardhat_artifacts() {
+        let foundry = json!({
+            "abi": [],
+            "bytecode": { "object": "0x6080604052", "linkReferences": {} },
+            "deployedBytecode": { "object": "0x6080" },
+            "metadata": {
+                "compiler": { "version": "0.8.24+commit.e11b9ed9" },
+                "language": "Solidity",
+                "settings": {
+                    "compilationTarget": { "src/Token.sol": "Token" },
+                    "optimizer": { "enabled": true, "runs": 200 },
+                    "evmVersion": "cancun"
+                }
+            }
+        });
+        let artifact = Artifact::from_json(&foundry, "Token.json", &[]).unwrap();
+        assert_eq!(artifact.format, ArtifactFormat::Foundry);
+        assert_eq!(artifact.contract.as_deref(), Some("Token"));
+        assert_eq!(
+            artifact.compiler.as_deref(),
+            Some("Solidity 0.8.24+commit.e11b9ed9, optimizer 200 runs, evm cancun")
+        );
+        assert_eq!(artifact.init_code.bytes, vec![0x60, 0x80, 0x60, 0x40, 0x52]);
+
+        let hardhat = json!({
+            "_format": "hh-sol-artifact-1",
+            "contractName": "Token",
+            "bytecode": "0x6080604052",
+            "deployedBytecode": "0x6080"
+        });
+        let artifact = Artifact::from_json(&hardhat, "Token.json", &[]).unwrap();
+        assert_eq!(artifact.format, ArtifactFormat::Hardhat);
+        assert_eq!(artifact.contract.as_deref(), Some("Token"));
+        assert_eq!(artifact.init_code.bytes, vec![0x60, 0x80, 0x60, 0x40, 0x52]);
+    }
+
+    #[test]
+    fn test_vyper_and_huff_artifacts() {
+        let combined = json!({
+            "version": "0.3.10+commit.91361694",
+            "contracts/Token.vy": { "bytecode": "0x6100aa", "bytecode_runtime": "0x00" }
+        });
+        let artifact = Artifact::from_json(&combined, "combined.json", &[]).unwrap();
+        assert_eq!(artifact.format, ArtifactFormat::Vyper);
+        assert_eq!(artifact.contract.as_deref(), Some("contracts/Token.vy"));
+        assert_eq!(
+            artifact.compiler.as_deref(),
+            Some("Vyper 0.3.10+commit.91361694")
+        );
+
+        // huffc artifacts store hex without 0x
+        let huff = json!({ "bytecode": "600a8060093d393df3", "runtime": "3d", "file": {} });
+        let artifact = Artifact::from_json(&huff, "Token.huff.json", &[]).unwrap();
+        assert_eq!(artifact.format, ArtifactFormat::Huff);
+        assert_eq!(artifact.init_code.bytes.len(), 9);
+    }
+
+    #[test]
+    fn test_linked_artifact() {
+        let bytecode = format!("0x60{}60", placeholder("src/Math.sol:Math"));
+        let foundry = json!({
+            "bytecode": {
+                "object": bytecode,
+                "linkReferences": { "src/Math.sol": { "Math": [{ "start": 1, "length": 20 }] } }
+            }
+        });
+
+        let link = LibraryLink::parse("Math=0x1111111111111111111111111111111111111111").unwrap();
+        let artifact = Artifact::from_json(&foundry, "Token.json", &[link]).unwrap();
+        assert_eq!(artifact.init_code.bytes.len(), 22);
+        assert_eq!(&artifact.init_code.bytes[1..21], &[0x11; 20]);
+
+        let error = Artifact::from_json(&foundry, "Token.json", &[]).unwrap_err();
+        assert!(format!("{:#}", error).contains("src/Math.sol:Math"));
+    }
+
+    #[test]
+    fn test_invalid_artifacts() {
+        let unlinked = json!({ "bytecode": { "object": "0x60__$1234$__60" } });
+        assert!(Artifact::from_json(&unlinked, "a.json", &[]).is_err());
+
+        let interface = json!({ "bytecode": { "object": "0x" } });
+        assert!(Artifact::from_json(&interface, "a.json", &[]).is_err());
+
+        assert!(Artifact::from_json(&json!({ "abi": [] }), "a.json", &[]).is_err());
+        assert!(Artifact::load("/nonexistent/artifact.json", &[]).is_err());
+    }
+}
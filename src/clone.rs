@@ -0,0 +1,141 @@
+use alloy::hex;
+use alloy::primitives::Address;
+use anyhow::Result;
+
+use crate::config::CloneKind;
+
+/// ERC-1167 runtime around the implementation address
+const ERC1167_RUNTIME: ([u8; 10], [u8; 15]) = (
+    hex!("363d3d373d3d3d363d73"),
+    hex!("5af43d82803e903d91602b57fd5bf3"),
+);
+
+/// Solady ERC-1967 runtime after the implementation slot push; the slot word starts at
+/// runtime offset 9, where the constructor reads it back for the SSTORE
+const ERC1967_RUNTIME: ([u8; 9], [u8; 52]) = (
+    hex!("363d3d373d3d363d7f"),
+    hex!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc545af43d6000803e6038573d6000fd5b3d6000f3"),
+);
+
+/// EIP-170 limit on deployed code; also keeps the length within the `PUSH2` prefix
+const MAX_RUNTIME_SIZE: usize = 0x6000;
+
+/// Creation code of a deterministic clone of `implementation`.
+///
+/// `args` selects the immutable-args variant (`Clones.cloneDeterministicWithImmutableArgs`,
+/// `LibClone.cloneDeterministic(impl, args, salt)`), which differs from the plain clone even
+/// when empty.
+pub fn clone_init_code(
+    kind: CloneKind,
+    implementation: &Address,
+    args: Option<&[u8]>,
+) -> Result<Vec<u8>> {
+    let mut runtime = match kind {
+        CloneKind::Erc1167 => [
+            &ERC1167_RUNTIME.0[..],
+            implementation.as_slice(),
+            &ERC1167_RUNTIME.1,
+        ]
+        .concat(),
+        CloneKind::Erc1967 => [&ERC1967_RUNTIME.0[..], &ERC1967_RUNTIME.1].concat(),
+    };
+    runtime.extend_from_slice(args.unwrap_or_default());
+    if runtime.len() > MAX_RUNTIME_SIZE {
+        anyhow::bail!(
+            "Clone runtime with immutable args is {} bytes, over the EIP-170 limit of {}",
+            runtime.len(),
+            MAX_RUNTIME_SIZE
+        );
+    }
+    let size = (runtime.len() as u16).to_be_bytes();
+
+    let constructor = match (kind, args) {
+        // RETURNDATASIZE PUSH1 0x2d DUP1 PUSH1 0x0a ... CODECOPY RETURN
+        (CloneKind::Erc1167, None) => hex!("3d602d80600a3d3981f3").to_vec(),
+        (CloneKind::Erc1167, Some(_)) => [&[0x61][..], &size, &hex!("3d81600a3d39f3")].concat(),
+        // Copy the runtime, then store the implementation in the ERC-1967 slot
+        (CloneKind::Erc1967, None) => [
+            &hex!("603d3d8160223d3973")[..],
+            implementation.as_slice(),
+            &hex!("60095155f3"),
+        ]
+        .concat(),
+        (CloneKind::Erc1967, Some(_)) => [
+            &[0x61][..],
+            &size,
+            &hex!("3d8160233d3973"),
+            implementation.as_slice(),
+            &hex!("60095155f3"),
+        ]
+        .concat(),
+    };
+    Ok([constructor, runtime].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, b256, bytes, keccak256, U256};
+
+    const IMPLEMENTATION: Address = address!("bebebebebebebebebebebebebebebebebebebebe");
+
+    #[test]
+    fn test_erc1167_init_code() {
+        let init_code = clone_init_code(CloneKind::Erc1167, &IMPLEMENTATION, None).unwrap();
+        assert_eq!(
+            init_code,
+            bytes!("3d602d80600a3d3981f3363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3")
+        );
+
+        let with_args =
+            clone_init_code(CloneKind::Erc1167, &IMPLEMENTATION, Some(&[0xaa, 0xbb])).unwrap();
+        assert_eq!(&with_args[..3], &[0x61, 0x00, 0x2d + 2]);
+        assert_eq!(
+            &with_args[10..],
+            &[&init_code[10..], &[0xaa, 0xbb][..]].concat()
+        );
+        assert_ne!(
+            clone_init_code(CloneKind::Erc1167, &IMPLEMENTATION, Some(&[])).unwrap(),
+            init_code
+        );
+    }
+
+    #[test]
+    fn test_erc1967_init_code() {
+        let init_code = clone_init_code(CloneKind::Erc1967, &IMPLEMENTATION, None).unwrap();
+        assert_eq!(init_code.len(), 0x22 + 0x3d);
+        assert_eq!(&init_code[9..29], IMPLEMENTATION.as_slice());
+        // The runtime pushes the ERC-1967 implementation slot
+        let slot = U256::from_be_bytes(keccak256("eip1967.proxy.implementation").0) - U256::from(1);
+        assert_eq!(&init_code[0x22 + 9..0x22 + 41], &slot.to_be_bytes::<32>());
+        // Solady `LibClone.ERC1967_CODE_HASH`
+        assert_eq!(
+            keccak256(&init_code[0x22..]),
+            b256!("aaa52c8cc8a0e3fd27ce756cc6b4e70c51423e9b597b11f32d3e49f8b1fc890d")
+        );
+
+        // The longer length push moves the runtime, and the CODECOPY offset, by one byte
+        let with_args =
+            clone_init_code(CloneKind::Erc1967, &IMPLEMENTATION, Some(&[0xaa])).unwrap();
+        assert_eq!(&with_args[..7], &hex!("61003e3d816023"));
+        assert_eq!(
+            &with_args[0x23..],
+            &[&init_code[0x22..], &[0xaa][..]].concat()
+        );
+    }
+
+    #[test]
+    fn test_clone_args_limit() {
+        // OpenZeppelin's limit: 24576 bytes of code minus the 45-byte ERC-1167 runtime
+        let args = vec![0; 24531];
+        let init_code = clone_init_code(CloneKind::Erc1167, &IMPLEMENTATION, Some(&args)).unwrap();
+        assert_eq!(&init_code[..3], &[0x61, 0x60, 0x00]);
+        let args = vec![0; 24532];
+        assert!(clone_init_code(CloneKind::Erc1167, &IMPLEMENTATION, Some(&args)).is_err());
+
+        let args = vec![0; 0x6000 - 0x3d];
+        assert!(clone_init_code(CloneKind::Erc1967, &IMPLEMENTATION, Some(&args)).is_ok());
+        let args = vec![0; 0x6000 - 0x3d + 1];
+        assert!(clone_init_code(CloneKind::Erc1967, &IMPLEMENTATION, Some(&args)).is_err());
+    }
+}
@@ -1,5 +1,11 @@
-use alloy::primitives::{keccak256, Address, B256};
-use clap::{Parser, Subcommand};
+use alloy::primitives::{keccak256, Address, Keccak256, B256};
+use anyhow::Result;
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::address::CREATE3_PROXY_INITCODE_HASH;
+use crate::init_code::InitCodeVariation;
+use crate::pattern::AddressPattern;
+use crate::salt::SaltTemplate;
 
 #[derive(Parser)]
 #[command(name = "miner")]
@@ -13,76 +19,470 @@ pub struct Args {
 pub enum Commands {
     /// Mine CREATE2 addresses using bytecode hash
     Create2(Create2Args),
-    /// Mine CREATE3 addresses using URL string
+    /// Mine CREATE3 addresses (URL-namespaced salts or a factory flavor)
     Create3(Create3Args),
+    /// Mine CreateX salts with permissioned and cross-chain protected guards
+    Createx(CreatexArgs),
+    /// Scan upcoming nonces of a deployer for matching CREATE addresses
+    Create(CreateArgs),
+    /// Mine the saltNonce of a Safe deployed through SafeProxyFactory
+    Safe(SafeArgs),
+    /// Mine the salt of a counterfactual ERC-4337 smart account
+    Account(AccountArgs),
+    /// Mine the salt of a deterministic minimal proxy clone
+    Clone(CloneArgs),
+    /// Mine a Uniswap v4 hook address carrying exactly the given permission flags
+    V4Hook(V4HookArgs),
+    /// Mine zkSync Era CREATE2 addresses using the zk bytecode hash
+    ZksyncCreate2(ZksyncCreate2Args),
+    /// Generate EOA keys whose address matches the pattern
+    Eoa(EoaArgs),
+    /// Generate deployer keys whose CREATE deployment at a nonce matches the pattern
+    EoaDeployer(EoaDeployerArgs),
+    /// Generate BIP-39 mnemonics whose derived account matches the pattern
+    Mnemonic(MnemonicArgs),
+    /// Decrypt a mnemonic file written by `mnemonic`
+    DecryptMnemonic(DecryptMnemonicArgs),
+    /// Mine a keyless (Nick's method) deployment transaction
+    Keyless(KeylessArgs),
+    /// Add a split-key offset to a private key offline
+    CombineKey(CombineKeyArgs),
 }
 
 #[derive(Parser)]
+#[command(allow_missing_positional = true)]
 pub struct Create2Args {
     /// Factory contract address
     pub factory: String,
 
-    /// Bytecode hash (32 bytes hex, starting with 0x)
-    pub bytecode_hash: String,
+    /// Bytecode hash (32 bytes hex, starting with 0x); omit when using --init-code
+    pub bytecode_hash: Option<String>,
 
-    /// Desired address prefix (hex)
-    pub prefix: String,
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    #[command(flatten)]
+    pub search: SaltSearchArgs,
+
+    /// Init code to hash instead of BYTECODE_HASH: hex, @file or - for stdin
+    #[arg(long, conflicts_with = "bytecode_hash")]
+    pub init_code: Option<String>,
+
+    /// Mine a counter in init code bytes OFFSET:LEN (negative offset counts from the end)
+    /// instead of the salt
+    #[arg(
+        long,
+        allow_hyphen_values = true,
+        value_name = "OFFSET:LEN",
+        conflicts_with_all = ["bytecode_hash", "salt_template"]
+    )]
+    pub vary_init_code: Option<String>,
+
+    /// Fixed salt used with --vary-init-code
+    #[arg(long, requires = "vary_init_code")]
+    pub salt: Option<String>,
+
+    /// Build artifact (Foundry, Hardhat, solc, Vyper or Huff JSON) to take init code from
+    #[arg(long, conflicts_with_all = ["bytecode_hash", "init_code"])]
+    pub artifact: Option<String>,
+
+    /// Deployed library address for placeholders in the init code (Lib=0x..., repeatable)
+    #[arg(long, conflicts_with = "bytecode_hash")]
+    pub link: Vec<String>,
+
+    /// Constructor signature, e.g. "constructor(address,uint256)"; its --constructor-arg
+    /// values are ABI-encoded onto the init code
+    #[arg(long, value_name = "SIGNATURE", conflicts_with = "bytecode_hash")]
+    pub constructor: Option<String>,
+
+    /// Constructor argument value, repeated once per parameter in order
+    #[arg(
+        long = "constructor-arg",
+        value_name = "VALUE",
+        allow_hyphen_values = true,
+        requires = "constructor"
+    )]
+    pub constructor_args: Vec<String>,
+}
+
+#[derive(Parser)]
+#[command(allow_missing_positional = true)]
+pub struct Create3Args {
+    /// Factory contract address
+    pub factory: String,
+
+    /// URL string for salt computation (url flavor only)
+    pub url: Option<String>,
+
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    #[command(flatten)]
+    pub search: SaltSearchArgs,
+
+    /// CREATE3 implementation deriving the proxy and salt
+    #[arg(long, value_enum, default_value_t = Create3Flavor::Url)]
+    pub create3_flavor: Create3Flavor,
+
+    /// Account calling the factory (zefram, axelar and deployer-based recipes)
+    #[arg(long)]
+    pub deployer: Option<String>,
+
+    /// Proxy init code hash (required for axelar and custom)
+    #[arg(long)]
+    pub proxy_hash: Option<String>,
+
+    /// Salt pre-hashing for the custom flavor
+    #[arg(long, value_enum)]
+    pub salt_recipe: Option<SaltRecipeKind>,
+}
+
+#[derive(Parser)]
+#[command(allow_missing_positional = true)]
+pub struct CreatexArgs {
+    /// Bytecode hash for deployCreate2 (omit with --create3)
+    pub bytecode_hash: Option<String>,
+
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    /// Mine a deployCreate3 address instead of deployCreate2
+    #[arg(long)]
+    pub create3: bool,
+
+    /// Account calling CreateX (msg.sender)
+    #[arg(long)]
+    pub caller: Option<String>,
+
+    /// Put the caller in the first 20 salt bytes so only it can deploy
+    #[arg(long)]
+    pub permissioned: bool,
+
+    /// Set salt byte 21 to enable cross-chain redeploy protection
+    #[arg(long)]
+    pub cross_chain_protection: bool,
+
+    /// Chain id mixed into protected salts
+    #[arg(long)]
+    pub chain_id: Option<u64>,
+
+    /// CreateX deployment address
+    #[arg(long, default_value = "0xba5Ed099633D3B313e4D5F7bdc1305d3c28ba5Ed")]
+    pub factory: String,
+
+    #[command(flatten)]
+    pub search: SaltSearchArgs,
+}
 
-    /// Starting salt value
+#[derive(Parser)]
+pub struct CreateArgs {
+    /// Deployer account address
+    pub deployer: String,
+
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    /// Deployer's next nonce
     #[arg(long, default_value = "0")]
-    pub start_salt: u64,
+    pub start_nonce: u64,
 
-    /// Maximum iterations
-    #[arg(long, default_value = "10000000000")]
-    pub max_iterations: u64,
+    /// Number of nonces to scan
+    #[arg(long, default_value = "1000000")]
+    pub max_nonces: u64,
 
-    /// Batch size for processing
-    #[arg(long, default_value = "100000")]
-    pub batch_size: u64,
+    /// Stop after this many matching nonces
+    #[arg(long, default_value = "10")]
+    pub max_matches: usize,
 
-    /// Use random salts instead of sequential
+    /// Use zkSync Era's CREATE derivation from the deployment nonce
     #[arg(long)]
-    pub random: bool,
+    pub zksync: bool,
+}
 
-    /// Use case-sensitive matching with Ethereum checksum addresses (EIP-55)
+#[derive(Parser)]
+pub struct ZksyncCreate2Args {
+    /// Deploying contract address (the CREATE2 sender)
+    pub factory: String,
+
+    /// zkSync bytecode hash (versioned, 32 bytes hex)
+    pub bytecode_hash: String,
+
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    /// ABI-encoded constructor arguments passed to the ContractDeployer
+    #[arg(long, default_value = "0x")]
+    pub constructor_input: String,
+
+    #[command(flatten)]
+    pub search: SaltSearchArgs,
+}
+
+#[derive(Parser)]
+pub struct SafeArgs {
+    /// SafeProxyFactory address
+    pub factory: String,
+
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    /// Safe singleton (master copy) the proxy delegates to
     #[arg(long)]
-    pub case_sensitive: bool,
+    pub singleton: String,
 
-    /// Match postfix/suffix instead of prefix
+    /// Factory's proxyCreationCode(): hex, @file or - for stdin
     #[arg(long)]
-    pub postfix: bool,
+    pub proxy_creation_code: String,
 
-    /// Postfix pattern for dual prefix+postfix matching (hex)
+    /// Owner addresses, comma-separated
+    #[arg(long, value_delimiter = ',', required = true)]
+    pub owners: Vec<String>,
+
+    /// Number of owner confirmations required
+    #[arg(long, default_value = "1")]
+    pub threshold: u64,
+
+    /// Fallback handler address
     #[arg(long)]
-    pub postfix_pattern: Option<String>,
+    pub fallback_handler: Option<String>,
+
+    /// Delegate call target run during setup (e.g. to enable modules)
+    #[arg(long)]
+    pub setup_to: Option<String>,
+
+    /// Calldata for --setup-to
+    #[arg(long, default_value = "0x", requires = "setup_to")]
+    pub setup_data: String,
+
+    #[command(flatten)]
+    pub search: SaltSearchArgs,
 }
 
 #[derive(Parser)]
-pub struct Create3Args {
-    /// Factory contract address
+pub struct CloneArgs {
+    /// Contract deploying the clone (the one calling cloneDeterministic)
+    pub deployer: String,
+
+    /// Implementation the clone delegates to
+    pub implementation: String,
+
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    /// Proxy layout of the clone
+    #[arg(long, value_enum, default_value = "erc1167")]
+    pub kind: CloneKind,
+
+    /// Immutable args appended to the clone's runtime (hex); selects the with-args variant
+    #[arg(long)]
+    pub args: Option<String>,
+
+    #[command(flatten)]
+    pub search: SaltSearchArgs,
+}
+
+#[derive(Parser)]
+pub struct V4HookArgs {
+    /// Hook permissions, comma-separated (beforeSwap,afterSwap,...); all others must be clear
+    #[arg(long, value_delimiter = ',', required = true)]
+    pub flags: Vec<String>,
+
+    #[command(subcommand)]
+    pub deployment: HookDeployment,
+}
+
+/// Factory the hook is deployed through. The vanity prefix becomes an optional `--prefix`,
+/// so the flags alone are a complete pattern and the positionals stay unambiguous.
+#[derive(Subcommand)]
+pub enum HookDeployment {
+    /// Deploy the hook with CREATE2
+    #[command(allow_missing_positional = false, mut_arg("prefix", hook_prefix))]
+    Create2(Create2Args),
+    /// Deploy the hook with CREATE3
+    #[command(allow_missing_positional = false, mut_arg("prefix", hook_prefix))]
+    Create3(Create3Args),
+}
+
+fn hook_prefix(arg: clap::Arg) -> clap::Arg {
+    arg.long("prefix")
+        .required(false)
+        .default_value("")
+        .help("Vanity prefix on top of the flags (hex, '?' matches any nibble)")
+}
+
+#[derive(Parser)]
+pub struct AccountArgs {
+    /// Account factory address
     pub factory: String,
 
-    /// URL string for salt computation
-    pub url: String,
+    #[command(flatten)]
+    pub pattern: PatternArgs,
 
-    /// Desired address prefix (hex)
-    pub prefix: String,
+    /// Factory convention for the proxy init code and account salt
+    #[arg(long, value_enum)]
+    pub preset: AccountPreset,
+
+    /// Account implementation the proxy points to
+    #[arg(long)]
+    pub implementation: String,
 
-    /// Starting salt value
+    /// Account owner addresses, comma-separated
+    #[arg(long, value_delimiter = ',')]
+    pub owner: Vec<String>,
+
+    /// Initialization calldata (kernel: createAccount data, custom: proxy init data)
+    #[arg(long)]
+    pub init_call: Option<String>,
+
+    /// Creation code of the factory's proxy (simple, custom): hex, @file or - for stdin
+    #[arg(long)]
+    pub proxy_creation_code: Option<String>,
+
+    #[command(flatten)]
+    pub search: SaltSearchArgs,
+}
+
+#[derive(Parser)]
+pub struct EoaArgs {
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    /// Maximum number of keys to try
+    #[arg(long, default_value = "10000000000")]
+    pub max_iterations: u64,
+
+    /// Directory the encrypted keystore is written to
+    #[arg(long, default_value = "keystore")]
+    pub keystore_dir: String,
+
+    /// File containing the keystore password (prompted for when omitted)
+    #[arg(long)]
+    pub password_file: Option<String>,
+
+    /// Also print the private key in plaintext
+    #[arg(long)]
+    pub print_private_key: bool,
+
+    /// Split-key mode: mine an offset for this public key instead of a full key
+    #[arg(long, conflicts_with_all = ["password_file", "print_private_key"])]
+    pub public_key: Option<String>,
+}
+
+#[derive(Parser)]
+pub struct EoaDeployerArgs {
+    #[command(flatten)]
+    pub eoa: EoaArgs,
+
+    /// Nonce of the deployment whose contract address must match
     #[arg(long, default_value = "0")]
-    pub start_salt: u64,
+    pub nonce: u64,
+}
 
-    /// Maximum iterations
+#[derive(Parser)]
+pub struct MnemonicArgs {
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    /// BIP-32 derivation path of the matched account
+    #[arg(long, default_value = crate::mnemonic::DEFAULT_DERIVATION_PATH)]
+    pub derivation_path: String,
+
+    /// Number of words (12, 15, 18, 21 or 24)
+    #[arg(long, default_value = "12")]
+    pub word_count: usize,
+
+    /// Maximum number of mnemonics to try
     #[arg(long, default_value = "10000000000")]
     pub max_iterations: u64,
 
-    /// Batch size for processing
-    #[arg(long, default_value = "100000")]
-    pub batch_size: u64,
+    /// Directory the encrypted mnemonic file is written to
+    #[arg(long, default_value = "keystore")]
+    pub output_dir: String,
 
-    /// Use random salts instead of sequential
+    /// File containing the encryption password (prompted for when omitted)
     #[arg(long)]
-    pub random: bool,
+    pub password_file: Option<String>,
+
+    /// Write the mnemonic file unencrypted instead
+    #[arg(long, conflicts_with = "password_file")]
+    pub plaintext: bool,
+
+    /// Also print the mnemonic in plaintext
+    #[arg(long)]
+    pub print_mnemonic: bool,
+}
+
+#[derive(Parser)]
+pub struct DecryptMnemonicArgs {
+    /// Encrypted mnemonic file (<address>.mnemonic.json)
+    pub file: String,
+
+    /// File containing the password (prompted for when omitted)
+    #[arg(long)]
+    pub password_file: Option<String>,
+
+    /// BIP-32 derivation path of the account shown alongside the phrase
+    #[arg(long, default_value = crate::mnemonic::DEFAULT_DERIVATION_PATH)]
+    pub derivation_path: String,
+}
+
+#[derive(Parser)]
+pub struct KeylessArgs {
+    #[command(flatten)]
+    pub pattern: PatternArgs,
+
+    /// Creation bytecode: hex, @file or - for stdin
+    #[arg(long)]
+    pub init_code: String,
+
+    /// Gas price in wei (the lowest value when mining the gas price)
+    #[arg(long, default_value = "100000000000")]
+    pub gas_price: u128,
+
+    /// Gas limit of the deployment
+    #[arg(long, default_value = "1000000")]
+    pub gas_limit: u64,
+
+    /// Transaction field that is mined
+    #[arg(long, value_enum, default_value = "r")]
+    pub vary: KeylessVariation,
+
+    /// Address the pattern applies to
+    #[arg(long, value_enum, default_value = "contract")]
+    pub target: KeylessTarget,
+
+    /// Maximum number of transactions to try
+    #[arg(long, default_value = "10000000000")]
+    pub max_iterations: u64,
+}
+
+#[derive(Parser)]
+pub struct CombineKeyArgs {
+    /// Offset found by `eoa --public-key` (32 bytes hex)
+    pub offset: String,
+
+    /// File containing the private key matching the public key (prompted for when omitted)
+    #[arg(long)]
+    pub private_key_file: Option<String>,
+
+    /// Directory the encrypted keystore is written to
+    #[arg(long, default_value = "keystore")]
+    pub keystore_dir: String,
+
+    /// File containing the keystore password (prompted for when omitted)
+    #[arg(long)]
+    pub password_file: Option<String>,
+
+    /// Also print the combined private key in plaintext
+    #[arg(long)]
+    pub print_private_key: bool,
+}
+
+/// Address pattern options shared by every command
+#[derive(Parser)]
+pub struct PatternArgs {
+    /// Desired address prefix (hex, '?' matches any nibble)
+    pub prefix: String,
 
     /// Use case-sensitive matching with Ethereum checksum addresses (EIP-55)
     #[arg(long)]
@@ -95,48 +495,158 @@ pub struct Create3Args {
     /// Postfix pattern for dual prefix+postfix matching (hex)
     #[arg(long)]
     pub postfix_pattern: Option<String>,
+
+    /// Bit mask (hex, zero-padded on the left) of an `address & mask == value` constraint;
+    /// repeatable, paired with --value in order
+    #[arg(long)]
+    pub mask: Vec<String>,
+
+    /// Required value of the bits selected by the corresponding --mask
+    #[arg(long)]
+    pub value: Vec<String>,
+}
+
+impl PatternArgs {
+    /// Address pattern from the prefix/postfix options and the --mask/--value constraints
+    pub fn compile(&self) -> Result<AddressPattern> {
+        if self.postfix && self.postfix_pattern.is_some() {
+            anyhow::bail!(
+                "Cannot use both --postfix and --postfix-pattern flags. Use --postfix-pattern for dual matching."
+            );
+        }
+        AddressPattern::from_args(
+            &self.prefix,
+            self.postfix_pattern.as_deref(),
+            self.postfix,
+            self.case_sensitive,
+        )?
+        .and(&AddressPattern::from_bit_constraints(
+            &self.mask,
+            &self.value,
+        )?)
+    }
+
+    /// The prefix is matched at the end of the address (--postfix)
+    pub fn postfix_only(&self) -> bool {
+        self.postfix && self.postfix_pattern.is_none()
+    }
+
+    /// Both the prefix and --postfix-pattern must match
+    pub fn dual_matching(&self) -> bool {
+        self.postfix_pattern.is_some()
+    }
+}
+
+/// Salt search options shared by the salt miners
+#[derive(Parser)]
+pub struct SaltSearchArgs {
+    /// Starting salt: counter value (decimal or hex) or a full 32-byte salt
+    #[arg(long, default_value = "0")]
+    pub start_salt: String,
+
+    /// Salt layout: 64 nibbles, hex for fixed and '?' for mined (contiguous) nibbles
+    #[arg(long)]
+    pub salt_template: Option<String>,
+
+    /// Maximum iterations
+    #[arg(long, default_value = "10000000000")]
+    pub max_iterations: u64,
+
+    /// Initial batch size for processing (adapted at runtime)
+    #[arg(long, default_value = "100000")]
+    pub batch_size: u64,
+
+    /// Use random salts instead of sequential
+    #[arg(long)]
+    pub random: bool,
 }
 
 // Common arguments extraction trait
 pub trait CommonArgs: Sync {
     fn factory(&self) -> &str;
-    fn prefix(&self) -> &str;
-    fn start_salt(&self) -> u64;
-    fn max_iterations(&self) -> u64;
-    fn batch_size(&self) -> u64;
-    fn random(&self) -> bool;
-    fn case_sensitive(&self) -> bool;
-    fn postfix(&self) -> bool;
-    fn postfix_pattern(&self) -> &Option<String>;
+    fn pattern(&self) -> &PatternArgs;
+    fn search(&self) -> &SaltSearchArgs;
+
+    /// Label of the mined value passed to the factory
+    fn raw_salt_label(&self) -> &'static str {
+        "Raw Salt"
+    }
+
+    /// Label of the salt actually used in the address derivation
+    fn final_salt_label(&self) -> &'static str {
+        "Final Salt"
+    }
 }
 
 impl CommonArgs for Create2Args {
     fn factory(&self) -> &str {
         &self.factory
     }
-    fn prefix(&self) -> &str {
-        &self.prefix
+    fn pattern(&self) -> &PatternArgs {
+        &self.pattern
+    }
+    fn search(&self) -> &SaltSearchArgs {
+        &self.search
+    }
+}
+
+impl CommonArgs for SafeArgs {
+    fn factory(&self) -> &str {
+        &self.factory
     }
-    fn start_salt(&self) -> u64 {
-        self.start_salt
+    fn pattern(&self) -> &PatternArgs {
+        &self.pattern
     }
-    fn max_iterations(&self) -> u64 {
-        self.max_iterations
+    fn search(&self) -> &SaltSearchArgs {
+        &self.search
     }
-    fn batch_size(&self) -> u64 {
-        self.batch_size
+    fn raw_salt_label(&self) -> &'static str {
+        "Salt Nonce"
     }
-    fn random(&self) -> bool {
-        self.random
+    fn final_salt_label(&self) -> &'static str {
+        "CREATE2 Salt"
     }
-    fn case_sensitive(&self) -> bool {
-        self.case_sensitive
+}
+
+impl CommonArgs for CloneArgs {
+    fn factory(&self) -> &str {
+        &self.deployer
     }
-    fn postfix(&self) -> bool {
-        self.postfix
+    fn pattern(&self) -> &PatternArgs {
+        &self.pattern
     }
-    fn postfix_pattern(&self) -> &Option<String> {
-        &self.postfix_pattern
+    fn search(&self) -> &SaltSearchArgs {
+        &self.search
+    }
+}
+
+impl CommonArgs for AccountArgs {
+    fn factory(&self) -> &str {
+        &self.factory
+    }
+    fn pattern(&self) -> &PatternArgs {
+        &self.pattern
+    }
+    fn search(&self) -> &SaltSearchArgs {
+        &self.search
+    }
+    fn raw_salt_label(&self) -> &'static str {
+        "Account Salt"
+    }
+    fn final_salt_label(&self) -> &'static str {
+        "CREATE2 Salt"
+    }
+}
+
+impl CommonArgs for ZksyncCreate2Args {
+    fn factory(&self) -> &str {
+        &self.factory
+    }
+    fn pattern(&self) -> &PatternArgs {
+        &self.pattern
+    }
+    fn search(&self) -> &SaltSearchArgs {
+        &self.search
     }
 }
 
@@ -144,66 +654,274 @@ impl CommonArgs for Create3Args {
     fn factory(&self) -> &str {
         &self.factory
     }
-    fn prefix(&self) -> &str {
-        &self.prefix
+    fn pattern(&self) -> &PatternArgs {
+        &self.pattern
     }
-    fn start_salt(&self) -> u64 {
-        self.start_salt
+    fn search(&self) -> &SaltSearchArgs {
+        &self.search
     }
-    fn max_iterations(&self) -> u64 {
-        self.max_iterations
+}
+
+impl CommonArgs for CreatexArgs {
+    fn factory(&self) -> &str {
+        &self.factory
     }
-    fn batch_size(&self) -> u64 {
-        self.batch_size
+    fn pattern(&self) -> &PatternArgs {
+        &self.pattern
     }
-    fn random(&self) -> bool {
-        self.random
+    fn search(&self) -> &SaltSearchArgs {
+        &self.search
     }
-    fn case_sensitive(&self) -> bool {
-        self.case_sensitive
+    fn final_salt_label(&self) -> &'static str {
+        "Guarded Salt"
     }
-    fn postfix(&self) -> bool {
-        self.postfix
+}
+
+/// Field of a keyless deployment that is varied to move the sender address
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum KeylessVariation {
+    /// Signature r value, keeping the transaction itself fixed
+    R,
+    /// Gas price, keeping the signature fixed
+    GasPrice,
+}
+
+/// Address a keyless deployment is matched on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum KeylessTarget {
+    /// The contract the transaction creates
+    Contract,
+    /// The one-time sender that has to be funded
+    Sender,
+}
+
+/// ERC-4337 account factories with known proxy and salt conventions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum AccountPreset {
+    /// eth-infinitism SimpleAccountFactory: ERC1967Proxy with initialize(owner), raw salt
+    Simple,
+    /// Alchemy LightAccountFactory v2: Solady ERC-1967 proxy, keccak256(owner ++ salt)
+    Light,
+    /// ZeroDev Kernel v3 factory: Solady ERC-1967 proxy, keccak256(initData ++ salt)
+    Kernel,
+    /// Coinbase Smart Wallet: Solady ERC-1967 proxy, keccak256(abi.encode(owners, nonce))
+    Coinbase,
+    /// Proxy creation code deployed with (implementation, --init-call), raw salt
+    Custom,
+}
+
+impl AccountPreset {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Simple => "simple",
+            Self::Light => "light",
+            Self::Kernel => "kernel",
+            Self::Coinbase => "coinbase",
+            Self::Custom => "custom",
+        }
     }
-    fn postfix_pattern(&self) -> &Option<String> {
-        &self.postfix_pattern
+}
+
+/// Minimal proxy layouts built by OpenZeppelin `Clones` and Solady `LibClone`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CloneKind {
+    /// ERC-1167 minimal proxy (`Clones.cloneDeterministic`, `LibClone.cloneDeterministic`)
+    Erc1167,
+    /// Solady ERC-1967 proxy (`LibClone.deployDeterministicERC1967`)
+    Erc1967,
+}
+
+/// CREATE3 implementations with known proxy and salt conventions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Create3Flavor {
+    /// keccak256(url ++ salt) through Solady's CREATE3
+    Url,
+    /// Solady CREATE3 library, salt used as-is
+    Solady,
+    /// 0xSequence create3 library, salt used as-is
+    Sequence,
+    /// ZeframLou CREATE3Factory: keccak256(abi.encodePacked(deployer, salt))
+    Zefram,
+    /// CreateX deployCreate3 with an unprotected salt: keccak256(abi.encode(salt))
+    Createx,
+    /// Axelar Create3Deployer: keccak256(abi.encode(deployer, salt))
+    Axelar,
+    /// Proxy hash from --proxy-hash and salt recipe from --salt-recipe
+    Custom,
+}
+
+impl Create3Flavor {
+    /// Salt recipe of this flavor; `custom` is the --salt-recipe choice
+    pub fn salt_recipe(
+        self,
+        url: Option<&str>,
+        deployer: Option<&Address>,
+        custom: Option<SaltRecipeKind>,
+    ) -> Result<SaltRecipe> {
+        let kind = match self {
+            Self::Url => SaltRecipeKind::Url,
+            Self::Solady | Self::Sequence => SaltRecipeKind::Raw,
+            Self::Zefram => SaltRecipeKind::PackedDeployer,
+            Self::Createx => SaltRecipeKind::Hash,
+            Self::Axelar => SaltRecipeKind::EncodedDeployer,
+            Self::Custom => custom
+                .ok_or_else(|| anyhow::anyhow!("--create3-flavor custom requires --salt-recipe"))?,
+        };
+        if custom.is_some() && self != Self::Custom {
+            anyhow::bail!("--salt-recipe is only supported with --create3-flavor custom");
+        }
+
+        match (kind, url) {
+            (SaltRecipeKind::Url, None) => anyhow::bail!("The url salt recipe requires a URL"),
+            (SaltRecipeKind::Url, Some(_)) => {}
+            (_, Some(url)) => anyhow::bail!(
+                "Unexpected URL '{}': only the url salt recipe uses one",
+                url
+            ),
+            (_, None) => {}
+        }
+
+        let deployer = || {
+            deployer
+                .ok_or_else(|| anyhow::anyhow!("The {:?} salt recipe requires --deployer", kind))
+        };
+        Ok(match kind {
+            SaltRecipeKind::Raw => SaltRecipe::Raw,
+            SaltRecipeKind::Url => SaltRecipe::url(url.unwrap_or_default().as_bytes()),
+            SaltRecipeKind::Hash => SaltRecipe::hashed(),
+            SaltRecipeKind::PackedDeployer => SaltRecipe::packed_deployer(deployer()?),
+            SaltRecipeKind::EncodedDeployer => SaltRecipe::encoded_deployer(deployer()?),
+        })
+    }
+
+    /// Proxy init code hash of this flavor; `custom` is the --proxy-hash value
+    pub fn proxy_init_code_hash(self, custom: Option<B256>) -> Result<B256> {
+        match (self, custom) {
+            (Self::Axelar | Self::Custom, Some(hash)) => Ok(hash),
+            (Self::Axelar | Self::Custom, None) => {
+                anyhow::bail!(
+                    "--create3-flavor {} requires --proxy-hash",
+                    format!("{:?}", self).to_lowercase()
+                )
+            }
+            (_, Some(_)) => {
+                anyhow::bail!("--proxy-hash is only supported with the axelar and custom flavors")
+            }
+            (_, None) => Ok(CREATE3_PROXY_INITCODE_HASH),
+        }
+    }
+}
+
+/// Salt pre-hashing recipes selectable for the custom CREATE3 flavor
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum SaltRecipeKind {
+    /// salt
+    Raw,
+    /// keccak256(url ++ salt)
+    Url,
+    /// keccak256(abi.encode(salt))
+    Hash,
+    /// keccak256(abi.encodePacked(deployer, salt))
+    PackedDeployer,
+    /// keccak256(abi.encode(deployer, salt))
+    EncodedDeployer,
+}
+
+/// How a raw salt becomes the salt used in the CREATE2 derivation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SaltRecipe {
+    /// Salt used as-is
+    Raw,
+    /// keccak256(prefix ++ salt ++ suffix)
+    Hashed { prefix: Vec<u8>, suffix: Vec<u8> },
+}
+
+impl SaltRecipe {
+    /// keccak256(prefix ++ salt)
+    pub fn prefixed(prefix: &[u8]) -> Self {
+        Self::Hashed {
+            prefix: prefix.to_vec(),
+            suffix: Vec::new(),
+        }
+    }
+
+    /// keccak256(url ++ salt)
+    pub fn url(url: &[u8]) -> Self {
+        Self::prefixed(url)
+    }
+
+    /// keccak256(abi.encode(salt))
+    pub fn hashed() -> Self {
+        Self::prefixed(&[])
+    }
+
+    /// keccak256(abi.encodePacked(deployer, salt))
+    pub fn packed_deployer(deployer: &Address) -> Self {
+        Self::prefixed(deployer.as_slice())
+    }
+
+    /// keccak256(abi.encode(deployer, salt))
+    pub fn encoded_deployer(deployer: &Address) -> Self {
+        Self::prefixed(deployer.into_word().as_slice())
+    }
+
+    /// keccak256(keccak256(initializer) ++ salt), as in `SafeProxyFactory.createProxyWithNonce`
+    pub fn initializer(initializer: &[u8]) -> Self {
+        Self::prefixed(keccak256(initializer).as_slice())
+    }
+
+    pub fn apply(&self, salt: &B256) -> B256 {
+        match self {
+            Self::Raw => *salt,
+            Self::Hashed { prefix, suffix } => {
+                let mut hasher = Keccak256::new();
+                hasher.update(prefix);
+                hasher.update(salt);
+                hasher.update(suffix);
+                hasher.finalize()
+            }
+        }
     }
 }
 
 #[derive(Clone)]
 pub struct MinerConfig {
     pub factory_address: Address,
+    /// Init code hash for CREATE2; unused for CREATE3
     pub url_or_bytecode_bytes: Vec<u8>,
-    pub prefix_bytes: Vec<u8>,
-    pub prefix_len: usize,
-    pub postfix_bytes: Vec<u8>,
-    pub postfix_len: usize,
+    /// Prefix/postfix patterns compiled for matching
+    pub pattern: AddressPattern,
     pub mode: MiningMode,
     pub case_sensitive: bool,
     pub postfix_only: bool, // true when using --postfix flag (legacy behavior)
     pub dual_matching: bool, // true when using both prefix and postfix patterns
+    /// Layout of the raw salt and its mined region
+    pub salt_template: SaltTemplate,
+    /// Turns the raw salt into the CREATE2 salt
+    pub salt_recipe: SaltRecipe,
+    /// Init code hash of the CREATE3 proxy
+    pub proxy_init_code_hash: B256,
 }
 
 #[derive(Clone, Debug)]
 pub enum MiningMode {
     Create2,
     Create3,
+    /// CREATE2 with a fixed salt, mining a counter inside the init code
+    InitCode(InitCodeVariation),
+    /// zkSync Era CREATE2 of the zk bytecode hash in `url_or_bytecode_bytes`
+    ZksyncCreate2 {
+        constructor_input_hash: B256,
+    },
 }
 
 impl MinerConfig {
+    /// Value hashed into the CREATE2 preimage for a raw salt: the salt itself, or the
+    /// init code hash when mining init code variations
     pub fn compute_final_salt(&self, salt: &B256) -> B256 {
-        match self.mode {
-            MiningMode::Create2 => {
-                // For CREATE2, salt is used directly
-                *salt
-            }
-            MiningMode::Create3 => {
-                // For CREATE3, salt is combined with URL
-                let mut packed = Vec::with_capacity(self.url_or_bytecode_bytes.len() + 32);
-                packed.extend_from_slice(&self.url_or_bytecode_bytes);
-                packed.extend_from_slice(salt.as_slice());
-                keccak256(&packed)
-            }
+        match &self.mode {
+            MiningMode::InitCode(variation) => variation.init_code_hash(salt),
+            _ => self.salt_recipe.apply(salt),
         }
     }
 }
@@ -211,7 +929,7 @@ impl MinerConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy::primitives::{address, b256};
+    use alloy::primitives::{address, b256, keccak256};
 
     #[test]
     fn test_miner_config_compute_final_salt_create2() {
@@ -221,14 +939,14 @@ mod tests {
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
             )
             .to_vec(),
-            prefix_bytes: b"dead".to_vec(),
-            prefix_len: 4,
-            postfix_bytes: Vec::new(),
-            postfix_len: 0,
+            pattern: AddressPattern::prefix("dead", false).unwrap(),
             mode: MiningMode::Create2,
             case_sensitive: false,
             postfix_only: false,
             dual_matching: false,
+            salt_template: SaltTemplate::default(),
+            salt_recipe: SaltRecipe::Raw,
+            proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
         };
 
         let salt = b256!("0000000000000000000000000000000000000000000000000000000000000123");
@@ -242,15 +960,15 @@ mod tests {
     fn test_miner_config_compute_final_salt_create3() {
         let config = MinerConfig {
             factory_address: address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8"),
-            url_or_bytecode_bytes: b"https://example.com".to_vec(),
-            prefix_bytes: b"cafe".to_vec(),
-            prefix_len: 4,
-            postfix_bytes: Vec::new(),
-            postfix_len: 0,
+            url_or_bytecode_bytes: Vec::new(),
+            pattern: AddressPattern::prefix("cafe", false).unwrap(),
             mode: MiningMode::Create3,
             case_sensitive: false,
             postfix_only: false,
             dual_matching: false,
+            salt_template: SaltTemplate::default(),
+            salt_recipe: SaltRecipe::url(b"https://example.com"),
+            proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
         };
 
         let salt = b256!("0000000000000000000000000000000000000000000000000000000000000123");
@@ -270,6 +988,69 @@ mod tests {
         assert_ne!(final_salt, different_final_salt);
     }
 
+    #[test]
+    fn test_create3_flavor_salt_recipes() {
+        let deployer = address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8");
+        let salt = b256!("0000000000000000000000000000000000000000000000000000000000000123");
+        let recipe = |flavor: Create3Flavor, url, custom| {
+            flavor
+                .salt_recipe(url, Some(&deployer), custom)
+                .unwrap()
+                .apply(&salt)
+        };
+
+        assert_eq!(recipe(Create3Flavor::Solady, None, None), salt);
+        assert_eq!(recipe(Create3Flavor::Sequence, None, None), salt);
+        assert_eq!(
+            recipe(Create3Flavor::Url, Some("https://example.com"), None),
+            keccak256([b"https://example.com".as_slice(), salt.as_slice()].concat())
+        );
+        assert_eq!(
+            recipe(Create3Flavor::Zefram, None, None),
+            keccak256([deployer.as_slice(), salt.as_slice()].concat())
+        );
+        assert_eq!(recipe(Create3Flavor::Createx, None, None), keccak256(salt));
+        assert_eq!(
+            recipe(Create3Flavor::Axelar, None, None),
+            keccak256([deployer.into_word(), salt].concat())
+        );
+        assert_eq!(
+            recipe(Create3Flavor::Custom, None, Some(SaltRecipeKind::Hash)),
+            keccak256(salt)
+        );
+    }
+
+    #[test]
+    fn test_create3_flavor_validation() {
+        let deployer = address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8");
+
+        assert!(Create3Flavor::Url.salt_recipe(None, None, None).is_err());
+        assert!(Create3Flavor::Solady
+            .salt_recipe(Some("https://example.com"), None, None)
+            .is_err());
+        assert!(Create3Flavor::Zefram.salt_recipe(None, None, None).is_err());
+        assert!(Create3Flavor::Custom.salt_recipe(None, None, None).is_err());
+        assert!(Create3Flavor::Solady
+            .salt_recipe(None, Some(&deployer), Some(SaltRecipeKind::Raw))
+            .is_err());
+
+        let hash = B256::repeat_byte(0x11);
+        assert_eq!(
+            Create3Flavor::Solady.proxy_init_code_hash(None).unwrap(),
+            CREATE3_PROXY_INITCODE_HASH
+        );
+        assert_eq!(
+            Create3Flavor::Axelar
+                .proxy_init_code_hash(Some(hash))
+                .unwrap(),
+            hash
+        );
+        assert!(Create3Flavor::Axelar.proxy_init_code_hash(None).is_err());
+        assert!(Create3Flavor::Createx
+            .proxy_init_code_hash(Some(hash))
+            .is_err());
+    }
+
     #[test]
     fn test_mining_mode_debug() {
         // Test that MiningMode implements Debug trait
@@ -278,5 +1059,74 @@ mod tests {
 
         assert!(format!("{:?}", mode1).contains("Create2"));
         assert!(format!("{:?}", mode2).contains("Create3"));
+
+        let variation = InitCodeVariation::new(vec![0; 40], "0:4", B256::ZERO).unwrap();
+        assert!(format!("{:?}", MiningMode::InitCode(variation)).contains("InitCode"));
+    }
+
+    #[test]
+    fn test_pattern_args_compile() {
+        let args = PatternArgs::parse_from(["pattern", "ab", "--mask", "0f", "--value", "03"]);
+        let pattern = args.compile().unwrap();
+        assert!(pattern.matches(&address!("ab00000000000000000000000000000000000003")));
+        assert!(!pattern.matches(&address!("ab00000000000000000000000000000000000004")));
+        assert!(!args.postfix_only() && !args.dual_matching());
+
+        let args = PatternArgs::parse_from(["pattern", "ab", "--postfix"]);
+        assert!(args
+            .compile()
+            .unwrap()
+            .matches(&address!("00000000000000000000000000000000000000ab")));
+        assert!(args.postfix_only());
+
+        let args =
+            PatternArgs::parse_from(["pattern", "ab", "--postfix", "--postfix-pattern", "cd"]);
+        assert!(args.compile().is_err());
+    }
+
+    #[test]
+    fn test_v4_hook_prefix_is_optional() {
+        let factory = "0x4e59b44847b379578588920ca78fbf26c0b4956c";
+        let hash = format!("0x{}", "11".repeat(32));
+
+        let args =
+            V4HookArgs::parse_from(["hook", "--flags", "beforeSwap", "create2", factory, &hash]);
+        let HookDeployment::Create2(create2) = args.deployment else {
+            panic!("expected create2");
+        };
+        assert_eq!(create2.bytecode_hash.as_deref(), Some(hash.as_str()));
+        assert_eq!(create2.pattern.prefix, "");
+
+        let args = V4HookArgs::parse_from([
+            "hook",
+            "--flags",
+            "beforeSwap",
+            "create2",
+            factory,
+            "--init-code",
+            "0x6000",
+            "--prefix",
+            "ab",
+        ]);
+        let HookDeployment::Create2(create2) = args.deployment else {
+            panic!("expected create2");
+        };
+        assert!(create2.bytecode_hash.is_none());
+        assert_eq!(create2.pattern.prefix, "ab");
+
+        let args = V4HookArgs::parse_from([
+            "hook",
+            "--flags",
+            "beforeSwap",
+            "create3",
+            factory,
+            "--create3-flavor",
+            "solady",
+        ]);
+        let HookDeployment::Create3(create3) = args.deployment else {
+            panic!("expected create3");
+        };
+        assert!(create3.url.is_none());
+        assert_eq!(create3.pattern.prefix, "");
     }
 }
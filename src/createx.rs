@@ -0,0 +1,139 @@
+use alloy::primitives::{address, Address, B256, U256};
+use anyhow::Result;
+
+use crate::config::SaltRecipe;
+use crate::salt::{SaltTemplate, MINED};
+
+/// Canonical CreateX deployment, at the same address on every chain
+pub const CREATEX_ADDRESS: Address = address!("ba5Ed099633D3B313e4D5F7bdc1305d3c28ba5Ed");
+
+/// Salt bytes inspected by CreateX's `_guard`: sender (0..20) and protection flag (20)
+pub const GUARD_BYTES: usize = 21;
+
+/// Byte 21 value enabling cross-chain redeploy protection
+const PROTECTED: u8 = 0x01;
+
+/// Byte 21 value disabling cross-chain redeploy protection
+const UNPROTECTED: u8 = 0x00;
+
+/// Salt template with `sender` (or the zero address) and the protection flag fixed, mining
+/// the remaining 11 bytes
+pub fn salt_template(sender: Option<&Address>, protected: bool) -> SaltTemplate {
+    let sender = sender.copied().unwrap_or(Address::ZERO);
+    let flag = if protected { PROTECTED } else { UNPROTECTED };
+    let template = format!(
+        "0x{}{:02x}{}",
+        alloy::hex::encode(sender),
+        flag,
+        MINED.to_string().repeat(2 * (32 - GUARD_BYTES))
+    );
+    SaltTemplate::parse(&template).expect("valid CreateX salt template")
+}
+
+/// Salt recipe reproducing `CreateX._guard` for salts of `template` sent by `caller`.
+///
+/// The sender and flag bytes must be fixed by the template, since they select the recipe.
+/// Salts that CreateX rejects with `InvalidSalt` are an error here as well.
+pub fn guard_recipe(
+    template: &SaltTemplate,
+    caller: Option<&Address>,
+    chain_id: Option<u64>,
+) -> Result<SaltRecipe> {
+    if template.mined_bytes().start < GUARD_BYTES {
+        anyhow::bail!(
+            "Salt template {} mines the CreateX sender/flag bytes; only the last {} bytes may be mined",
+            template,
+            32 - GUARD_BYTES
+        );
+    }
+
+    let salt = template.base_salt();
+    let sender = Address::from_slice(&salt[..20]);
+    let flag = salt[20];
+    let chain_id = || {
+        chain_id
+            .map(|id| B256::from(U256::from(id)))
+            .ok_or_else(|| anyhow::anyhow!("Cross-chain redeploy protection requires --chain-id"))
+    };
+
+    let recipe = if caller == Some(&sender) {
+        let mut prefix = sender.into_word().to_vec();
+        match flag {
+            PROTECTED => prefix.extend_from_slice(chain_id()?.as_slice()),
+            UNPROTECTED => {}
+            _ => anyhow::bail!(
+                "CreateX rejects permissioned salts with flag byte 0x{:02x}",
+                flag
+            ),
+        }
+        SaltRecipe::prefixed(&prefix)
+    } else if sender.is_zero() {
+        match flag {
+            PROTECTED => SaltRecipe::prefixed(chain_id()?.as_slice()),
+            UNPROTECTED => SaltRecipe::hashed(),
+            _ => anyhow::bail!(
+                "CreateX rejects zero-sender salts with flag byte 0x{:02x}",
+                flag
+            ),
+        }
+    } else {
+        // Salt not bound to the caller: keccak256(abi.encode(salt))
+        SaltRecipe::hashed()
+    };
+    Ok(recipe)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::keccak256;
+
+    const CALLER: Address = address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8");
+
+    /// Reference `_guard` written out per branch
+    fn guard(salt: &B256, caller: &Address, chain_id: u64) -> B256 {
+        let chain = B256::from(U256::from(chain_id));
+        match (Address::from_slice(&salt[..20]), salt[20]) {
+            (sender, 0x01) if sender == *caller => {
+                keccak256([caller.into_word(), chain, *salt].concat())
+            }
+            (sender, 0x00) if sender == *caller => keccak256([caller.into_word(), *salt].concat()),
+            (Address::ZERO, 0x01) => keccak256([chain, *salt].concat()),
+            _ => keccak256(salt),
+        }
+    }
+
+    #[test]
+    fn test_guard_recipes_match_createx() {
+        let other = address!("1111111111111111111111111111111111111111");
+        for (sender, protected) in [
+            (Some(&CALLER), true),
+            (Some(&CALLER), false),
+            (None, true),
+            (None, false),
+            (Some(&other), true),
+        ] {
+            let template = salt_template(sender, protected);
+            let recipe = guard_recipe(&template, Some(&CALLER), Some(10)).unwrap();
+            for i in [0, 1, u64::MAX] {
+                let salt = template.salt_at(i);
+                assert_eq!(&salt[..20], sender.unwrap_or(&Address::ZERO).as_slice());
+                assert_eq!(recipe.apply(&salt), guard(&salt, &CALLER, 10));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_guard_salts() {
+        // Protection needs the chain id
+        assert!(guard_recipe(&salt_template(Some(&CALLER), true), Some(&CALLER), None).is_err());
+
+        // Flag bytes other than 0x00/0x01 revert for caller and zero-sender salts
+        let bad_flag = SaltTemplate::parse(&format!("0x{}02{}", "0".repeat(40), "?".repeat(22)));
+        assert!(guard_recipe(&bad_flag.unwrap(), Some(&CALLER), Some(1)).is_err());
+
+        // Mined region overlapping the flag byte
+        let overlapping = SaltTemplate::parse(&format!("0x{}{}", "0".repeat(40), "?".repeat(24)));
+        assert!(guard_recipe(&overlapping.unwrap(), Some(&CALLER), Some(1)).is_err());
+    }
+}
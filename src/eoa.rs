@@ -0,0 +1,284 @@
+use alloy::primitives::{keccak256, Address};
+use alloy::signers::local::PrivateKeySigner;
+use anyhow::Result;
+use k256::elliptic_curve::rand_core::OsRng;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::zeroize::{Zeroize, Zeroizing};
+use k256::elliptic_curve::BatchNormalize;
+use k256::{AffinePoint, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::address::get_create_address;
+use crate::pattern::AddressPattern;
+
+/// Consecutive public keys converted to affine coordinates with a single inversion
+const BATCH_SIZE: usize = 256;
+
+/// Keys walked from one random seed before a fresh seed is drawn
+const KEYS_PER_SEED: u64 = 1 << 24;
+
+/// Key whose address matches the pattern. The key is zeroized on drop.
+pub struct EoaMatch {
+    /// Private key, or in split-key searches the offset to add to the owner's key
+    pub secret_key: SecretKey,
+    pub address: Address,
+    /// Contract the account deploys at the searched nonce, when matching deployments
+    pub contract: Option<Address>,
+}
+
+/// Address of an uncompressed secp256k1 public key: keccak256(x ++ y)[12..]
+pub fn public_key_address(point: &AffinePoint) -> Address {
+    let encoded = point.to_encoded_point(false);
+    Address::from_slice(&keccak256(&encoded.as_bytes()[1..])[12..])
+}
+
+/// Search random keys on every thread of the current rayon pool until one matches
+/// `pattern`, `found` is set or about `max_keys` keys were checked.
+///
+/// Each worker draws a seed from the OS CSPRNG and walks `seed, seed + 1, ...` by adding
+/// G to the public key, so only the seed costs a full scalar multiplication.
+///
+/// With a `base` public key, the address of `base + k·G` is matched and only the offset
+/// `k` is returned, so the searcher never learns a usable key (split-key search). With a
+/// `nonce`, the pattern applies to the account's CREATE deployment at that nonce.
+pub fn search_keys(
+    pattern: &AddressPattern,
+    base: Option<&PublicKey>,
+    nonce: Option<u64>,
+    max_keys: u64,
+    found: &AtomicBool,
+    checked: &AtomicU64,
+) -> Option<EoaMatch> {
+    let result = Mutex::new(None);
+
+    rayon::broadcast(|_| {
+        while !found.load(Ordering::Relaxed) && checked.load(Ordering::Relaxed) < max_keys {
+            if let Some(eoa) = walk_random_seed(pattern, base, nonce, max_keys, found, checked) {
+                found.store(true, Ordering::Relaxed);
+                result.lock().unwrap().get_or_insert(eoa);
+            }
+        }
+    });
+
+    result.into_inner().unwrap()
+}
+
+fn walk_random_seed(
+    pattern: &AddressPattern,
+    base: Option<&PublicKey>,
+    nonce: Option<u64>,
+    max_keys: u64,
+    found: &AtomicBool,
+    checked: &AtomicU64,
+) -> Option<EoaMatch> {
+    let mut seed: Scalar = *NonZeroScalar::random(&mut OsRng);
+    let mut point = ProjectivePoint::GENERATOR * seed;
+    if let Some(base) = base {
+        point += base.as_affine();
+    }
+    let mut batch = [ProjectivePoint::IDENTITY; BATCH_SIZE];
+    let mut result = None;
+    let mut offset = 0u64;
+
+    while result.is_none()
+        && offset < KEYS_PER_SEED
+        && !found.load(Ordering::Relaxed)
+        && checked.load(Ordering::Relaxed) < max_keys
+    {
+        for slot in batch.iter_mut() {
+            *slot = point;
+            point += AffinePoint::GENERATOR;
+        }
+
+        let affine = ProjectivePoint::batch_normalize(&batch);
+        result = affine.iter().enumerate().find_map(|(i, public)| {
+            let address = public_key_address(public);
+            let contract = nonce.map(|nonce| get_create_address(&address, nonce));
+            if !pattern.matches(&contract.unwrap_or(address)) {
+                return None;
+            }
+            let secret = seed + Scalar::from(offset + i as u64);
+            let secret_key = Option::<NonZeroScalar>::from(NonZeroScalar::new(secret))?.into();
+            Some(EoaMatch {
+                secret_key,
+                address,
+                contract,
+            })
+        });
+
+        offset += BATCH_SIZE as u64;
+        checked.fetch_add(BATCH_SIZE as u64, Ordering::Relaxed);
+    }
+
+    seed.zeroize();
+    result
+}
+
+/// Parse a SEC1 public key (compressed or uncompressed), also accepting raw `x ++ y`
+pub fn parse_public_key(hex: &str) -> Result<PublicKey> {
+    let mut bytes = alloy::hex::decode(hex.trim())
+        .map_err(|e| anyhow::anyhow!("Invalid public key '{}': {}", hex, e))?;
+    if bytes.len() == 64 {
+        bytes.insert(0, 0x04);
+    }
+    PublicKey::from_sec1_bytes(&bytes)
+        .map_err(|_| anyhow::anyhow!("Invalid public key '{}': not a secp256k1 point", hex))
+}
+
+/// Parse a 32-byte hex private key or split-key offset
+pub fn parse_secret_key(hex: &str) -> Result<SecretKey> {
+    let bytes = Zeroizing::new(
+        alloy::hex::decode(hex.trim()).map_err(|_| anyhow::anyhow!("Invalid key: not hex"))?,
+    );
+    SecretKey::from_slice(&bytes).map_err(|_| anyhow::anyhow!("Invalid key: expected 32 bytes"))
+}
+
+/// Private key for a split-key match: the owner's key plus the mined offset
+pub fn combine_keys(private_key: &SecretKey, offset: &SecretKey) -> Result<SecretKey> {
+    let sum = *private_key.to_nonzero_scalar() + *offset.to_nonzero_scalar();
+    Option::<NonZeroScalar>::from(NonZeroScalar::new(sum))
+        .map(SecretKey::from)
+        .ok_or_else(|| anyhow::anyhow!("Offset cancels the private key"))
+}
+
+/// Encrypt `secret_key` into a Web3 Secret Storage v3 keystore named after its address
+/// inside `dir`, returning the file path
+pub fn write_keystore(dir: &Path, secret_key: &SecretKey, password: &[u8]) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let address = PrivateKeySigner::from(secret_key.clone()).address();
+    let name = alloy::hex::encode(address);
+    let (signer, _) = PrivateKeySigner::encrypt_keystore(
+        dir,
+        &mut OsRng,
+        secret_key.to_bytes(),
+        password,
+        Some(&name),
+    )?;
+    if signer.address() != address {
+        anyhow::bail!("Keystore does not round-trip to {}", address);
+    }
+    Ok(dir.join(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::address;
+
+    #[test]
+    fn test_public_key_address() {
+        // Well-known key 1
+        let point = (ProjectivePoint::GENERATOR * Scalar::ONE).to_affine();
+        assert_eq!(
+            public_key_address(&point),
+            address!("7e5f4552091a69125d5dfcb7b8c2659029395bdf")
+        );
+    }
+
+    #[test]
+    fn test_search_keys_returns_matching_key() {
+        let pattern = AddressPattern::prefix("ab", false).unwrap();
+        let found = AtomicBool::new(false);
+        let checked = AtomicU64::new(0);
+
+        let eoa = search_keys(&pattern, None, None, u64::MAX, &found, &checked).unwrap();
+
+        assert!(pattern.matches(&eoa.address));
+        let signer = PrivateKeySigner::from(eoa.secret_key.clone());
+        assert_eq!(signer.address(), eoa.address);
+        assert!(checked.load(Ordering::Relaxed) > 0);
+    }
+
+    #[test]
+    fn test_search_keys_respects_limit() {
+        let pattern = AddressPattern::prefix("ffffffffffff", false).unwrap();
+        let found = AtomicBool::new(false);
+        let checked = AtomicU64::new(0);
+
+        assert!(search_keys(&pattern, None, None, 1, &found, &checked).is_none());
+        // Each worker stops after the batch that crosses the limit
+        let checked = checked.load(Ordering::Relaxed) as usize;
+        assert!(checked >= BATCH_SIZE);
+        assert!(checked <= BATCH_SIZE * rayon::current_num_threads());
+    }
+
+    #[test]
+    fn test_search_deployer_keys() {
+        let pattern = AddressPattern::prefix("ef", false).unwrap();
+        let found = AtomicBool::new(false);
+        let checked = AtomicU64::new(0);
+
+        let eoa = search_keys(&pattern, None, Some(3), u64::MAX, &found, &checked).unwrap();
+
+        assert_eq!(
+            PrivateKeySigner::from(eoa.secret_key.clone()).address(),
+            eoa.address
+        );
+        let contract = eoa.contract.unwrap();
+        assert_eq!(contract, eoa.address.create(3));
+        assert!(pattern.matches(&contract));
+    }
+
+    #[test]
+    fn test_split_key_search() {
+        let owner = SecretKey::random(&mut OsRng);
+        let pattern = AddressPattern::prefix("cd", false).unwrap();
+        let found = AtomicBool::new(false);
+        let checked = AtomicU64::new(0);
+
+        let offset = search_keys(
+            &pattern,
+            Some(&owner.public_key()),
+            None,
+            u64::MAX,
+            &found,
+            &checked,
+        )
+        .unwrap();
+
+        // Only owner + offset controls the matched address
+        let combined = combine_keys(&owner, &offset.secret_key).unwrap();
+        assert_eq!(PrivateKeySigner::from(combined).address(), offset.address);
+        assert_ne!(
+            PrivateKeySigner::from(offset.secret_key.clone()).address(),
+            offset.address
+        );
+    }
+
+    #[test]
+    fn test_parse_keys() {
+        let key =
+            parse_secret_key("0x0000000000000000000000000000000000000000000000000000000000000001")
+                .unwrap();
+        let public = key.public_key();
+        let uncompressed = alloy::hex::encode(public.to_encoded_point(false).as_bytes());
+        let compressed = alloy::hex::encode(public.to_encoded_point(true).as_bytes());
+
+        assert_eq!(parse_public_key(&uncompressed).unwrap(), public);
+        assert_eq!(parse_public_key(&compressed).unwrap(), public);
+        assert_eq!(parse_public_key(&uncompressed[2..]).unwrap(), public);
+        assert!(parse_public_key("0x04").is_err());
+        assert!(parse_secret_key("0x00").is_err());
+
+        // Offset n - 1 cancels key 1
+        let negated =
+            parse_secret_key("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140")
+                .unwrap();
+        assert!(combine_keys(&key, &negated).is_err());
+    }
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let dir = std::env::temp_dir().join(format!("ca-miner-keystore-{}", std::process::id()));
+        let secret_key = SecretKey::random(&mut OsRng);
+
+        let path = write_keystore(&dir, &secret_key, b"password").unwrap();
+        let signer = PrivateKeySigner::decrypt_keystore(&path, "password").unwrap();
+        assert_eq!(signer.credential().to_bytes(), secret_key.to_bytes());
+        assert!(PrivateKeySigner::decrypt_keystore(&path, "wrong").is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
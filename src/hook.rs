@@ -0,0 +1,142 @@
+use alloy::primitives::{Address, U160};
+use anyhow::Result;
+
+use crate::pattern::AddressPattern;
+
+/// Low address bits the v4 PoolManager reads hook permissions from
+pub const ALL_HOOK_MASK: u16 = (1 << 14) - 1;
+
+/// Uniswap v4 `Hooks` permission flags, as named in `Hooks.Permissions`
+pub const HOOK_FLAGS: [(&str, u16); 14] = [
+    ("beforeInitialize", 1 << 13),
+    ("afterInitialize", 1 << 12),
+    ("beforeAddLiquidity", 1 << 11),
+    ("afterAddLiquidity", 1 << 10),
+    ("beforeRemoveLiquidity", 1 << 9),
+    ("afterRemoveLiquidity", 1 << 8),
+    ("beforeSwap", 1 << 7),
+    ("afterSwap", 1 << 6),
+    ("beforeDonate", 1 << 5),
+    ("afterDonate", 1 << 4),
+    ("beforeSwapReturnDelta", 1 << 3),
+    ("afterSwapReturnDelta", 1 << 2),
+    ("afterAddLiquidityReturnDelta", 1 << 1),
+    ("afterRemoveLiquidityReturnDelta", 1 << 0),
+];
+
+/// Delta flags and the permission the PoolManager requires alongside them
+const DELTA_REQUIREMENTS: [(&str, &str); 4] = [
+    ("beforeSwapReturnDelta", "beforeSwap"),
+    ("afterSwapReturnDelta", "afterSwap"),
+    ("afterAddLiquidityReturnDelta", "afterAddLiquidity"),
+    ("afterRemoveLiquidityReturnDelta", "afterRemoveLiquidity"),
+];
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .collect::<String>()
+        .to_ascii_lowercase()
+        // Flag constants spell it RETURNS_DELTA
+        .replace("returnsdelta", "returndelta")
+        .trim_end_matches("flag")
+        .to_string()
+}
+
+fn flag_bit(name: &str) -> Option<u16> {
+    HOOK_FLAGS
+        .iter()
+        .find(|(flag, _)| normalize(flag) == normalize(name))
+        .map(|(_, bit)| *bit)
+}
+
+/// Parse flag names (`beforeSwap`, `before_swap`, `BEFORE_SWAP_FLAG`, ...) into the flag bits.
+/// Rejects combinations the PoolManager refuses as an invalid hook address.
+pub fn parse_hook_flags(names: &[String]) -> Result<u16> {
+    let mut flags = 0;
+    for name in names {
+        let bit = flag_bit(name).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unknown hook flag '{}' (expected one of: {})",
+                name,
+                HOOK_FLAGS.map(|(flag, _)| flag).join(", ")
+            )
+        })?;
+        flags |= bit;
+    }
+    if flags == 0 {
+        anyhow::bail!("A hook needs at least one permission flag");
+    }
+    for (delta, base) in DELTA_REQUIREMENTS {
+        if flags & flag_bit(delta).unwrap() != 0 && flags & flag_bit(base).unwrap() == 0 {
+            anyhow::bail!("Hook flag {} requires {}", delta, base);
+        }
+    }
+    Ok(flags)
+}
+
+/// Names of the flags set in `flags`
+pub fn hook_flag_names(flags: u16) -> Vec<&'static str> {
+    HOOK_FLAGS
+        .iter()
+        .filter(|(_, bit)| flags & bit != 0)
+        .map(|(flag, _)| *flag)
+        .collect()
+}
+
+/// Pattern requiring exactly `flags` in the low 14 bits and every other permission bit clear
+pub fn hook_pattern(flags: u16) -> AddressPattern {
+    AddressPattern::bits(
+        &Address::from(U160::from(ALL_HOOK_MASK)),
+        &Address::from(U160::from(flags & ALL_HOOK_MASK)),
+    )
+    .expect("hook flags lie within the hook mask")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::address;
+
+    #[test]
+    fn test_parse_hook_flags() {
+        let names = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(
+            parse_hook_flags(&names(&["beforeSwap", "AFTER_SWAP_FLAG"])).unwrap(),
+            0xc0
+        );
+        assert_eq!(
+            parse_hook_flags(&names(&["afterSwap", "after_swap_returns_delta"])).unwrap(),
+            0x44
+        );
+        assert_eq!(
+            hook_flag_names(0x2080),
+            vec!["beforeInitialize", "beforeSwap"]
+        );
+
+        assert!(parse_hook_flags(&names(&["beforeSwapp"])).is_err());
+        assert!(parse_hook_flags(&names(&[])).is_err());
+        assert!(parse_hook_flags(&names(&["beforeSwapReturnDelta"])).is_err());
+    }
+
+    #[test]
+    fn test_hook_pattern() {
+        let pattern = hook_pattern(0xc0);
+
+        assert!(pattern.matches(&address!("00000000000000000000000000000000000000c0")));
+        assert!(pattern.matches(&address!("abcdef00000000000000000000000000001fc0c0")));
+        // Extra permission bits are as wrong as missing ones
+        assert!(!pattern.matches(&address!("00000000000000000000000000000000000000c1")));
+        assert!(!pattern.matches(&address!("0000000000000000000000000000000000000080")));
+        assert!(!pattern.matches(&address!("00000000000000000000000000000000000020c0")));
+        assert_eq!(pattern.constrained_bits(), 14);
+
+        let with_prefix = AddressPattern::prefix("beef", false)
+            .unwrap()
+            .and(&pattern)
+            .unwrap();
+        assert!(with_prefix.matches(&address!("beef0000000000000000000000000000000040c0")));
+        assert!(!with_prefix.matches(&address!("dead0000000000000000000000000000000040c0")));
+    }
+}
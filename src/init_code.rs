@@ -0,0 +1,314 @@
+use alloy::dyn_abi::{JsonAbiExt, Specifier};
+use alloy::json_abi::Constructor;
+use alloy::primitives::{keccak256, B256};
+use anyhow::{Context, Result};
+use std::io::Read;
+
+use crate::link::{link_bytecode, LibraryLink};
+use crate::salt::SaltTemplate;
+
+/// EIP-3860 limit on init code size
+pub const MAX_INIT_CODE_SIZE: usize = 49_152;
+
+/// Contract creation code and where it came from
+#[derive(Clone, Debug)]
+pub struct InitCode {
+    /// Creation bytecode followed by any constructor arguments
+    pub bytes: Vec<u8>,
+    pub source: String,
+    /// ABI-encoded constructor arguments appended to the creation bytecode
+    pub constructor_args: Vec<u8>,
+}
+
+impl InitCode {
+    /// Read init code from `source`: hex, `@path` to a file holding hex, or `-` for stdin.
+    /// Library placeholders are resolved with `links`.
+    pub fn load(source: &str, links: &[LibraryLink]) -> Result<Self> {
+        let (text, source) = match source {
+            "-" => {
+                let mut text = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut text)
+                    .context("Failed to read init code from stdin")?;
+                (text, "stdin".to_string())
+            }
+            _ => match source.strip_prefix('@') {
+                Some(path) => (
+                    std::fs::read_to_string(path)
+                        .with_context(|| format!("Failed to read init code file '{}'", path))?,
+                    path.to_string(),
+                ),
+                None => (source.to_string(), "command line".to_string()),
+            },
+        };
+
+        Ok(Self {
+            bytes: parse_init_code_hex(&link_bytecode(text.trim(), links, &[])?)?,
+            source,
+            constructor_args: Vec::new(),
+        })
+    }
+
+    /// Append ABI-encoded constructor arguments to the creation bytecode
+    pub fn with_constructor_args(mut self, encoded: Vec<u8>) -> Self {
+        self.bytes.extend_from_slice(&encoded);
+        self.constructor_args.extend(encoded);
+        self
+    }
+
+    pub fn hash(&self) -> B256 {
+        keccak256(&self.bytes)
+    }
+
+    /// Whether the code is exactly 32 bytes, which is more likely a mistyped hash than init code
+    pub fn looks_like_hash(&self) -> bool {
+        self.bytes.len() == 32
+    }
+
+    /// Whether the code exceeds [`MAX_INIT_CODE_SIZE`] and would be rejected on mainnet
+    pub fn is_oversized(&self) -> bool {
+        self.bytes.len() > MAX_INIT_CODE_SIZE
+    }
+}
+
+/// Parse hex init code, ignoring an optional `0x` prefix and surrounding whitespace
+pub fn parse_init_code_hex(text: &str) -> Result<Vec<u8>> {
+    let text = text.trim();
+    let hex = text.strip_prefix("0x").unwrap_or(text);
+    if hex.is_empty() {
+        anyhow::bail!("Init code is empty");
+    }
+    if let Some(bad) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+        anyhow::bail!("Init code contains invalid hex character '{}'", bad);
+    }
+    if !hex.len().is_multiple_of(2) {
+        anyhow::bail!("Init code has an odd number of hex digits ({})", hex.len());
+    }
+
+    Ok(alloy::hex::decode(hex)?)
+}
+
+/// Init code with a mined counter in a byte range, deployed with a fixed CREATE2 salt.
+///
+/// Candidate `i` writes the low `len` bytes of the raw salt (the counter) into
+/// `init_code[offset..offset + len]`, big-endian.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InitCodeVariation {
+    pub init_code: Vec<u8>,
+    pub offset: usize,
+    pub len: usize,
+    /// Fixed CREATE2 salt
+    pub salt: B256,
+}
+
+impl InitCodeVariation {
+    /// `range` is `OFFSET:LEN`; a negative offset counts from the end of the init code
+    pub fn new(init_code: Vec<u8>, range: &str, salt: B256) -> Result<Self> {
+        let Some((offset, len)) = range.split_once(':') else {
+            anyhow::bail!("Invalid init code range '{}': expected OFFSET:LEN", range);
+        };
+        let offset: i64 = offset
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid offset in '{}': {}", range, e))?;
+        let len: usize = len
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid length in '{}': {}", range, e))?;
+
+        let offset = if offset < 0 {
+            init_code.len().checked_sub(offset.unsigned_abs() as usize)
+        } else {
+            Some(offset as usize)
+        };
+        let Some(offset) = offset.filter(|offset| offset + len <= init_code.len()) else {
+            anyhow::bail!(
+                "Init code range '{}' is outside the {}-byte init code",
+                range,
+                init_code.len()
+            );
+        };
+        if !(1..=32).contains(&len) {
+            anyhow::bail!("Init code range length must be 1 to 32 bytes, got {}", len);
+        }
+
+        Ok(Self {
+            init_code,
+            offset,
+            len,
+            salt,
+        })
+    }
+
+    /// Counter layout: the low `len` bytes of the raw salt
+    pub fn salt_template(&self) -> SaltTemplate {
+        let mined = 2 * self.len;
+        SaltTemplate::parse(&format!("{}{}", "0".repeat(64 - mined), "?".repeat(mined)))
+            .expect("valid init code counter template")
+    }
+
+    /// Init code bytes before the counter
+    pub fn prefix(&self) -> &[u8] {
+        &self.init_code[..self.offset]
+    }
+
+    /// Init code bytes after the counter
+    pub fn tail(&self) -> &[u8] {
+        &self.init_code[self.offset + self.len..]
+    }
+
+    /// Bytes of the raw salt written into the init code
+    pub fn counter<'a>(&self, raw_salt: &'a B256) -> &'a [u8] {
+        &raw_salt[32 - self.len..]
+    }
+
+    /// Init code for the candidate with counter `raw_salt`
+    pub fn init_code_at(&self, raw_salt: &B256) -> Vec<u8> {
+        let mut init_code = self.init_code.clone();
+        init_code[self.offset..self.offset + self.len].copy_from_slice(self.counter(raw_salt));
+        init_code
+    }
+
+    pub fn init_code_hash(&self, raw_salt: &B256) -> B256 {
+        keccak256(self.init_code_at(raw_salt))
+    }
+}
+
+/// ABI-encode `values` for a constructor signature like `constructor(address,uint256)`.
+///
+/// Values use the usual CLI forms: hex for addresses and bytes, decimal or hex for
+/// integers, `[a,b]` for arrays and `(a,b)` for tuples.
+pub fn encode_constructor_args(signature: &str, values: &[String]) -> Result<Vec<u8>> {
+    let signature = signature.trim();
+    let signature = if signature.starts_with('(') {
+        format!("constructor{}", signature)
+    } else {
+        signature.to_string()
+    };
+    let constructor = Constructor::parse(&signature)
+        .map_err(|e| anyhow::anyhow!("Invalid constructor signature '{}': {}", signature, e))?;
+
+    if constructor.inputs.len() != values.len() {
+        anyhow::bail!(
+            "Constructor '{}' takes {} arguments, got {}",
+            signature,
+            constructor.inputs.len(),
+            values.len()
+        );
+    }
+
+    let values = constructor
+        .inputs
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(i, (param, value))| {
+            param
+                .resolve()
+                .and_then(|ty| ty.coerce_str(value))
+                .map_err(|e| {
+                    anyhow::anyhow!(
+                        "Invalid constructor argument {} ({}) '{}': {}",
+                        i,
+                        param.ty,
+                        value,
+                        e
+                    )
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(constructor.abi_encode_input(&values)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_init_code_hex() {
+        assert_eq!(
+            parse_init_code_hex("  0x6080604052\n").unwrap(),
+            vec![0x60, 0x80, 0x60, 0x40, 0x52]
+        );
+        assert_eq!(parse_init_code_hex("00ff").unwrap(), vec![0x00, 0xff]);
+
+        assert!(parse_init_code_hex("").is_err());
+        assert!(parse_init_code_hex("0x").is_err());
+        assert!(parse_init_code_hex("0x608").is_err());
+        assert!(parse_init_code_hex("0x60zz").is_err());
+        // 32 bytes is unusual but valid init code
+        assert_eq!(
+            parse_init_code_hex(&"ab".repeat(32)).unwrap(),
+            vec![0xab; 32]
+        );
+    }
+
+    #[test]
+    fn test_load_init_code_from_file() {
+        let path = std::env::temp_dir().join(format!("ca-miner-init-code-{}", std::process::id()));
+        std::fs::write(&path, "0x6080604052\n").unwrap();
+
+        let init_code = InitCode::load(&format!("@{}", path.display()), &[]).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(init_code.bytes, vec![0x60, 0x80, 0x60, 0x40, 0x52]);
+        assert_eq!(init_code.hash(), keccak256([0x60, 0x80, 0x60, 0x40, 0x52]));
+        assert!(!init_code.is_oversized());
+        assert!(InitCode::load("@/nonexistent/init-code.hex", &[]).is_err());
+    }
+
+    #[test]
+    fn test_init_code_variation() {
+        let init_code: Vec<u8> = (0..40).collect();
+        let variation = InitCodeVariation::new(init_code.clone(), "-4:2", B256::ZERO).unwrap();
+        assert_eq!(variation.offset, 36);
+        assert_eq!(variation.tail(), &[38, 39]);
+
+        let counter = variation.salt_template().salt_at(0xabcd);
+        let varied = variation.init_code_at(&counter);
+        assert_eq!(&varied[..36], &init_code[..36]);
+        assert_eq!(&varied[36..], &[0xab, 0xcd, 38, 39]);
+        assert_eq!(variation.init_code_hash(&counter), keccak256(&varied));
+        assert_eq!(variation.salt_template().capacity(), Some(1 << 16));
+
+        assert!(InitCodeVariation::new(init_code.clone(), "39:2", B256::ZERO).is_err());
+        assert!(InitCodeVariation::new(init_code.clone(), "-41:1", B256::ZERO).is_err());
+        assert!(InitCodeVariation::new(init_code.clone(), "0:0", B256::ZERO).is_err());
+        assert!(InitCodeVariation::new(init_code, "0", B256::ZERO).is_err());
+    }
+
+    #[test]
+    fn test_encode_constructor_args() {
+        use alloy::primitives::{address, Address, U256};
+        use alloy::sol_types::SolValue;
+
+        let owner = address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8");
+        let encoded = encode_constructor_args(
+            "constructor(address,uint256,string)",
+            &[owner.to_string(), "42".to_string(), "name".to_string()],
+        )
+        .unwrap();
+        assert_eq!(
+            encoded,
+            (owner, U256::from(42), "name".to_string()).abi_encode_params()
+        );
+
+        // Signature without the keyword, arrays
+        let encoded =
+            encode_constructor_args("(address[])", &[format!("[{},{}]", owner, Address::ZERO)])
+                .unwrap();
+        assert_eq!(encoded, (vec![owner, Address::ZERO],).abi_encode_params());
+
+        let init_code = InitCode {
+            bytes: vec![0x60, 0x80],
+            source: "test".to_string(),
+            constructor_args: Vec::new(),
+        }
+        .with_constructor_args(vec![0xaa; 32]);
+        assert_eq!(init_code.bytes.len(), 34);
+        assert_eq!(init_code.constructor_args, vec![0xaa; 32]);
+
+        assert!(encode_constructor_args("constructor(address)", &[]).is_err());
+        assert!(encode_constructor_args("constructor(uint8)", &["256".to_string()]).is_err());
+        assert!(encode_constructor_args("function f(uint8)", &["1".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,471 @@
+use alloy::primitives::B256;
+
+/// Keccak-256 block size in bytes; lane suffixes must fit in one block
+pub const RATE: usize = 136;
+const RATE_WORDS: usize = RATE / 8;
+
+/// Widest lane count of any backend
+pub const MAX_LANES: usize = 8;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Combined rho and pi steps, unrolled so every rotation amount is a constant.
+/// Each entry is `(destination lane, rotate left by, 64 - rotate left by)`.
+macro_rules! rho_pi {
+    ($a:ident, $(($pi:literal, $left:literal, $right:literal)),* $(,)?) => {
+        let mut last = $a[1];
+        $(
+            let tmp = $a[$pi];
+            $a[$pi] = last.rotl::<$left, $right>();
+            last = tmp;
+        )*
+        let _ = last;
+    };
+}
+
+/// A vector of independent 64-bit keccak lanes, one per message being hashed
+trait Lanes: Copy {
+    /// Build from one word per lane
+    fn load(words: &[u64]) -> Self;
+    /// Write one word per lane
+    fn store(self, out: &mut [u64]);
+    fn splat(word: u64) -> Self;
+    fn xor(self, other: Self) -> Self;
+    /// `!self & other`
+    fn andnot(self, other: Self) -> Self;
+    /// Rotate left by `LEFT`; `RIGHT` must be `64 - LEFT`
+    fn rotl<const LEFT: i32, const RIGHT: i32>(self) -> Self;
+}
+
+impl Lanes for u64 {
+    #[inline(always)]
+    fn load(words: &[u64]) -> Self {
+        words[0]
+    }
+    #[inline(always)]
+    fn store(self, out: &mut [u64]) {
+        out[0] = self;
+    }
+    #[inline(always)]
+    fn splat(word: u64) -> Self {
+        word
+    }
+    #[inline(always)]
+    fn xor(self, other: Self) -> Self {
+        self ^ other
+    }
+    #[inline(always)]
+    fn andnot(self, other: Self) -> Self {
+        !self & other
+    }
+    #[inline(always)]
+    fn rotl<const LEFT: i32, const RIGHT: i32>(self) -> Self {
+        self.rotate_left(LEFT as u32)
+    }
+}
+
+/// keccak-f[1600] applied to every lane in parallel
+#[inline(always)]
+fn keccak_f<L: Lanes>(a: &mut [L; 25]) {
+    for rc in ROUND_CONSTANTS {
+        // Theta
+        let mut c = [L::splat(0); 5];
+        for x in 0..5 {
+            c[x] = a[x]
+                .xor(a[x + 5])
+                .xor(a[x + 10])
+                .xor(a[x + 15])
+                .xor(a[x + 20]);
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5].xor(c[(x + 1) % 5].rotl::<1, 63>());
+            for y in 0..5 {
+                a[y * 5 + x] = a[y * 5 + x].xor(d);
+            }
+        }
+
+        // Rho and pi
+        rho_pi!(
+            a,
+            (10, 1, 63),
+            (7, 3, 61),
+            (11, 6, 58),
+            (17, 10, 54),
+            (18, 15, 49),
+            (3, 21, 43),
+            (5, 28, 36),
+            (16, 36, 28),
+            (8, 45, 19),
+            (21, 55, 9),
+            (24, 2, 62),
+            (4, 14, 50),
+            (15, 27, 37),
+            (23, 41, 23),
+            (19, 56, 8),
+            (13, 8, 56),
+            (12, 25, 39),
+            (2, 43, 21),
+            (20, 62, 2),
+            (14, 18, 46),
+            (22, 39, 25),
+            (9, 61, 3),
+            (6, 20, 44),
+            (1, 44, 20),
+        );
+
+        // Chi
+        for y in 0..5 {
+            let row = [
+                a[y * 5],
+                a[y * 5 + 1],
+                a[y * 5 + 2],
+                a[y * 5 + 3],
+                a[y * 5 + 4],
+            ];
+            for x in 0..5 {
+                a[y * 5 + x] = row[x].xor(row[(x + 1) % 5].andnot(row[(x + 2) % 5]));
+            }
+        }
+
+        // Iota
+        a[0] = a[0].xor(L::splat(rc));
+    }
+}
+
+/// Keccak-256 sponge state after absorbing a common message prefix.
+///
+/// Full blocks are permuted eagerly; the remainder stays buffered so that
+/// [`KeccakBackend::finalize_lanes`] can append a different suffix per lane.
+#[derive(Clone)]
+pub struct KeccakMidstate {
+    state: [u64; 25],
+    buffer: [u8; RATE],
+    buffered: usize,
+}
+
+impl Default for KeccakMidstate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeccakMidstate {
+    pub const fn new() -> Self {
+        Self {
+            state: [0; 25],
+            buffer: [0; RATE],
+            buffered: 0,
+        }
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let take = (RATE - self.buffered).min(data.len());
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&data[..take]);
+            self.buffered += take;
+            data = &data[take..];
+
+            if self.buffered == RATE {
+                for (word, chunk) in self.state.iter_mut().zip(self.buffer.chunks_exact(8)) {
+                    *word ^= u64::from_le_bytes(chunk.try_into().unwrap());
+                }
+                keccak_f(&mut self.state);
+                self.buffered = 0;
+            }
+        }
+    }
+
+    pub fn finalize(&self) -> B256 {
+        let mut out = [B256::ZERO];
+        finalize_generic::<u64>(self, &[&[]], &mut out);
+        out[0]
+    }
+}
+
+/// Finish one keccak-256 per lane, each continuing `midstate` with its own suffix.
+///
+/// All suffixes must have the same length (at most one block), which keeps the block
+/// structure identical across lanes.
+#[inline(always)]
+fn finalize_generic<L: Lanes>(midstate: &KeccakMidstate, suffixes: &[&[u8]], out: &mut [B256]) {
+    let lanes = suffixes.len();
+    let suffix_len = suffixes[0].len();
+    assert!(lanes <= MAX_LANES && out.len() == lanes && suffix_len <= RATE);
+
+    let buffered = midstate.buffered;
+    let total = buffered + suffix_len;
+    let padded_len = (total / RATE + 1) * RATE;
+
+    let mut blocks = [[0u8; 2 * RATE]; MAX_LANES];
+    for (block, suffix) in blocks.iter_mut().zip(suffixes) {
+        assert_eq!(suffix.len(), suffix_len, "suffixes must have equal length");
+        block[..buffered].copy_from_slice(&midstate.buffer[..buffered]);
+        block[buffered..total].copy_from_slice(suffix);
+        block[total] ^= 0x01;
+        block[padded_len - 1] ^= 0x80;
+    }
+
+    let mut state: [L; 25] = std::array::from_fn(|i| L::splat(midstate.state[i]));
+    let mut words = [0u64; MAX_LANES];
+
+    for offset in (0..padded_len).step_by(RATE) {
+        for (w, lane_word) in state.iter_mut().enumerate().take(RATE_WORDS) {
+            for (word, block) in words.iter_mut().zip(&blocks).take(lanes) {
+                let pos = offset + w * 8;
+                *word = u64::from_le_bytes(block[pos..pos + 8].try_into().unwrap());
+            }
+            *lane_word = lane_word.xor(L::load(&words[..lanes]));
+        }
+        keccak_f(&mut state);
+    }
+
+    for (w, lane_word) in state.iter().enumerate().take(4) {
+        lane_word.store(&mut words[..lanes]);
+        for (hash, word) in out.iter_mut().zip(&words) {
+            hash[w * 8..w * 8 + 8].copy_from_slice(&word.to_le_bytes());
+        }
+    }
+}
+
+/// Keccak implementation used for lane-parallel hashing, picked at runtime
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeccakBackend {
+    Scalar,
+    Avx2,
+    Avx512,
+}
+
+impl KeccakBackend {
+    /// Pick the widest backend supported by the running CPU
+    pub fn detect() -> Self {
+        Self::available().pop().unwrap_or(Self::Scalar)
+    }
+
+    /// Backends usable on the running CPU, narrowest first
+    pub fn available() -> Vec<Self> {
+        [Self::Scalar, Self::Avx2, Self::Avx512]
+            .into_iter()
+            .filter(|backend| backend.is_supported())
+            .collect()
+    }
+
+    /// Whether the running CPU has the instructions this backend needs
+    pub fn is_supported(self) -> bool {
+        match self {
+            Self::Scalar => true,
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => std::arch::is_x86_feature_detected!("avx2"),
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx512 => std::arch::is_x86_feature_detected!("avx512f"),
+            #[cfg(not(target_arch = "x86_64"))]
+            _ => false,
+        }
+    }
+
+    /// Number of messages hashed per call to [`Self::finalize_lanes`]
+    pub fn lanes(self) -> usize {
+        match self {
+            Self::Scalar => 1,
+            Self::Avx2 => 4,
+            Self::Avx512 => 8,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Scalar => "scalar",
+            Self::Avx2 => "AVX2",
+            Self::Avx512 => "AVX-512",
+        }
+    }
+
+    /// Hash `midstate ++ suffixes[i]` into `out[i]` for each of the [`Self::lanes`] lanes.
+    ///
+    /// Suffixes must all have the same length, no longer than the 136-byte keccak rate.
+    pub fn finalize_lanes(self, midstate: &KeccakMidstate, suffixes: &[&[u8]], out: &mut [B256]) {
+        assert_eq!(suffixes.len(), self.lanes());
+        assert!(
+            self.is_supported(),
+            "{} is not supported by this CPU",
+            self.name()
+        );
+        match self {
+            Self::Scalar => finalize_generic::<u64>(midstate, suffixes, out),
+            // SAFETY: the CPU feature required by each backend was checked above
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => unsafe { x86::finalize_avx2(midstate, suffixes, out) },
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx512 => unsafe { x86::finalize_avx512(midstate, suffixes, out) },
+            #[cfg(not(target_arch = "x86_64"))]
+            _ => unreachable!("SIMD keccak backends require x86_64"),
+        }
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::{finalize_generic, KeccakMidstate, Lanes};
+    use alloy::primitives::B256;
+    use std::arch::x86_64::*;
+
+    // The lane types below must only be used inside the `target_feature` functions at the
+    // bottom of this module, where the required instructions are known to be available.
+
+    #[derive(Clone, Copy)]
+    struct Avx2Lanes(__m256i);
+
+    impl Lanes for Avx2Lanes {
+        #[inline(always)]
+        fn load(words: &[u64]) -> Self {
+            assert_eq!(words.len(), 4);
+            Self(unsafe { _mm256_loadu_si256(words.as_ptr().cast()) })
+        }
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            assert_eq!(out.len(), 4);
+            unsafe { _mm256_storeu_si256(out.as_mut_ptr().cast(), self.0) }
+        }
+        #[inline(always)]
+        fn splat(word: u64) -> Self {
+            Self(unsafe { _mm256_set1_epi64x(word as i64) })
+        }
+        #[inline(always)]
+        fn xor(self, other: Self) -> Self {
+            Self(unsafe { _mm256_xor_si256(self.0, other.0) })
+        }
+        #[inline(always)]
+        fn andnot(self, other: Self) -> Self {
+            Self(unsafe { _mm256_andnot_si256(self.0, other.0) })
+        }
+        #[inline(always)]
+        fn rotl<const LEFT: i32, const RIGHT: i32>(self) -> Self {
+            unsafe {
+                let left = _mm256_slli_epi64::<LEFT>(self.0);
+                let right = _mm256_srli_epi64::<RIGHT>(self.0);
+                Self(_mm256_or_si256(left, right))
+            }
+        }
+    }
+
+    #[derive(Clone, Copy)]
+    struct Avx512Lanes(__m512i);
+
+    impl Lanes for Avx512Lanes {
+        #[inline(always)]
+        fn load(words: &[u64]) -> Self {
+            assert_eq!(words.len(), 8);
+            Self(unsafe { _mm512_loadu_si512(words.as_ptr().cast()) })
+        }
+        #[inline(always)]
+        fn store(self, out: &mut [u64]) {
+            assert_eq!(out.len(), 8);
+            unsafe { _mm512_storeu_si512(out.as_mut_ptr().cast(), self.0) }
+        }
+        #[inline(always)]
+        fn splat(word: u64) -> Self {
+            Self(unsafe { _mm512_set1_epi64(word as i64) })
+        }
+        #[inline(always)]
+        fn xor(self, other: Self) -> Self {
+            Self(unsafe { _mm512_xor_si512(self.0, other.0) })
+        }
+        #[inline(always)]
+        fn andnot(self, other: Self) -> Self {
+            Self(unsafe { _mm512_andnot_si512(self.0, other.0) })
+        }
+        #[inline(always)]
+        fn rotl<const LEFT: i32, const RIGHT: i32>(self) -> Self {
+            Self(unsafe { _mm512_rol_epi64::<LEFT>(self.0) })
+        }
+    }
+
+    #[target_feature(enable = "avx2")]
+    pub(super) fn finalize_avx2(midstate: &KeccakMidstate, suffixes: &[&[u8]], out: &mut [B256]) {
+        finalize_generic::<Avx2Lanes>(midstate, suffixes, out)
+    }
+
+    #[target_feature(enable = "avx512f")]
+    pub(super) fn finalize_avx512(midstate: &KeccakMidstate, suffixes: &[&[u8]], out: &mut [B256]) {
+        finalize_generic::<Avx512Lanes>(midstate, suffixes, out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::keccak256;
+
+    #[test]
+    fn test_midstate_matches_keccak256() {
+        let data: Vec<u8> = (0..400u32).map(|i| (i * 7 + 3) as u8).collect();
+        for len in [0, 1, 32, 135, 136, 137, 271, 272, 400] {
+            let mut midstate = KeccakMidstate::new();
+            // Feed in uneven pieces to exercise buffering
+            for piece in data[..len].chunks(50) {
+                midstate.update(piece);
+            }
+            assert_eq!(midstate.finalize(), keccak256(&data[..len]), "len {}", len);
+        }
+    }
+
+    #[test]
+    fn test_finalize_lanes_matches_keccak256_on_all_backends() {
+        for backend in KeccakBackend::available() {
+            let lanes = backend.lanes();
+            for (prefix_len, suffix_len) in [(0, 23), (0, 85), (19, 32), (130, 32), (300, 136)] {
+                let prefix: Vec<u8> = (0..prefix_len).map(|i| i as u8).collect();
+                let suffixes: Vec<Vec<u8>> = (0..lanes)
+                    .map(|l| (0..suffix_len).map(|i| (i * 31 + l * 17) as u8).collect())
+                    .collect();
+                let suffix_refs: Vec<&[u8]> = suffixes.iter().map(Vec::as_slice).collect();
+
+                let mut midstate = KeccakMidstate::new();
+                midstate.update(&prefix);
+                let mut out = vec![B256::ZERO; lanes];
+                backend.finalize_lanes(&midstate, &suffix_refs, &mut out);
+
+                for (hash, suffix) in out.iter().zip(&suffixes) {
+                    let expected = keccak256([prefix.as_slice(), suffix].concat());
+                    assert_eq!(
+                        *hash, expected,
+                        "{:?} {}+{}",
+                        backend, prefix_len, suffix_len
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_backend_lanes() {
+        assert_eq!(KeccakBackend::Scalar.lanes(), 1);
+        assert_eq!(KeccakBackend::Avx2.lanes(), 4);
+        assert_eq!(KeccakBackend::Avx512.lanes(), 8);
+        assert!(KeccakBackend::available().contains(&KeccakBackend::detect()));
+    }
+}
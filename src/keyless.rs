@@ -0,0 +1,309 @@
+use alloy::consensus::transaction::RlpEcdsaEncodableTx;
+use alloy::consensus::{SignableTransaction, TxLegacy};
+use alloy::primitives::{Address, Bytes, Signature, TxKind, B256, U256};
+use anyhow::Result;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::address::get_create_address;
+use crate::config::{KeylessTarget, KeylessVariation};
+use crate::pattern::AddressPattern;
+use crate::scheduler::{ChunkSizer, SaltCursor};
+
+/// `r` and `s` used by the deterministic deployment proxy, mined values count up from `r`
+pub const KEYLESS_SIGNATURE_VALUE: U256 = U256::from_limbs([0x2222222222222222; 4]);
+
+/// Pre-EIP-155 contract creation signed with a made-up signature (Nick's method).
+///
+/// Nobody knows the sender's key, so the transaction can only ever deploy the init code,
+/// on any chain where the sender is funded.
+#[derive(Clone, Debug)]
+pub struct KeylessDeployment {
+    pub init_code: Bytes,
+    pub gas_price: u128,
+    pub gas_limit: u64,
+    pub variation: KeylessVariation,
+}
+
+/// Signed keyless transaction for one mined counter
+#[derive(Clone, Debug)]
+pub struct KeylessTransaction {
+    pub counter: u64,
+    pub sender: Address,
+    /// Contract the transaction creates (the sender's nonce 0)
+    pub contract: Address,
+    pub gas_price: u128,
+    pub gas_limit: u64,
+    /// Signature `r`; `s` is always [`KEYLESS_SIGNATURE_VALUE`] and `v` 27
+    pub r: U256,
+    pub raw_transaction: Bytes,
+}
+
+impl KeylessDeployment {
+    /// Fail when bumping the gas price for counters `0..count` would overflow it
+    pub fn check_counters(&self, count: u64) -> Result<()> {
+        if self.variation == KeylessVariation::GasPrice
+            && self
+                .gas_price
+                .checked_add(count.saturating_sub(1) as u128)
+                .is_none()
+        {
+            anyhow::bail!(
+                "--gas-price {} leaves no room to vary it over {} transactions",
+                self.gas_price,
+                count
+            );
+        }
+        Ok(())
+    }
+
+    /// Unsigned transaction for `counter`; `None` when the gas price would overflow
+    fn tx(&self, counter: u64) -> Option<TxLegacy> {
+        let gas_price = match self.variation {
+            KeylessVariation::R => self.gas_price,
+            KeylessVariation::GasPrice => self.gas_price.checked_add(counter as u128)?,
+        };
+        Some(TxLegacy {
+            chain_id: None,
+            nonce: 0,
+            gas_price,
+            gas_limit: self.gas_limit,
+            to: TxKind::Create,
+            value: U256::ZERO,
+            input: self.init_code.clone(),
+        })
+    }
+
+    /// Made-up signature for `counter`, which bumps `r` when mining it
+    fn signature(&self, counter: u64) -> Signature {
+        let r = match self.variation {
+            KeylessVariation::R => KEYLESS_SIGNATURE_VALUE + U256::from(counter),
+            KeylessVariation::GasPrice => KEYLESS_SIGNATURE_VALUE,
+        };
+        Signature::new(r, KEYLESS_SIGNATURE_VALUE, false)
+    }
+
+    /// Signature hash shared by every counter when only `r` varies, so a search hashes the
+    /// init code once instead of per counter
+    pub fn fixed_signature_hash(&self) -> Option<B256> {
+        match self.variation {
+            KeylessVariation::R => self.tx(0).map(|tx| tx.signature_hash()),
+            KeylessVariation::GasPrice => None,
+        }
+    }
+
+    /// Sender recovered for `counter`, or `None` when the signature is not valid
+    pub fn sender(&self, counter: u64) -> Option<Address> {
+        self.sender_with(counter, None)
+    }
+
+    /// [`Self::sender`] reusing a hash from [`Self::fixed_signature_hash`] when given
+    pub fn sender_with(&self, counter: u64, fixed_hash: Option<B256>) -> Option<Address> {
+        let hash = match fixed_hash {
+            Some(hash) => hash,
+            None => self.tx(counter)?.signature_hash(),
+        };
+        self.signature(counter)
+            .recover_address_from_prehash(&hash)
+            .ok()
+    }
+
+    /// Signed transaction for `counter`, ready to broadcast
+    pub fn transaction(&self, counter: u64) -> Option<KeylessTransaction> {
+        let tx = self.tx(counter)?;
+        let signature = self.signature(counter);
+        let sender = signature
+            .recover_address_from_prehash(&tx.signature_hash())
+            .ok()?;
+        let mut raw_transaction = Vec::new();
+        tx.rlp_encode_signed(&signature, &mut raw_transaction);
+        Some(KeylessTransaction {
+            counter,
+            sender,
+            contract: get_create_address(&sender, 0),
+            gas_price: tx.gas_price,
+            gas_limit: tx.gas_limit,
+            r: signature.r(),
+            raw_transaction: raw_transaction.into(),
+        })
+    }
+}
+
+impl KeylessTransaction {
+    pub fn hash(&self) -> B256 {
+        alloy::primitives::keccak256(&self.raw_transaction)
+    }
+
+    /// Wei the sender needs to pay for the deployment at full gas usage
+    pub fn funding(&self) -> U256 {
+        U256::from(self.gas_price) * U256::from(self.gas_limit)
+    }
+}
+
+/// Try counters claimed from `cursor` on every thread of the current rayon pool until the
+/// sender or its contract matches `pattern`
+pub fn search_keyless(
+    deployment: &KeylessDeployment,
+    pattern: &AddressPattern,
+    target: KeylessTarget,
+    cursor: &SaltCursor,
+    found: &AtomicBool,
+    checked: &AtomicU64,
+) -> Option<KeylessTransaction> {
+    let result = Mutex::new(None);
+    let fixed_hash = deployment.fixed_signature_hash();
+
+    rayon::broadcast(|ctx| {
+        let mut sizer = ChunkSizer::new(0, ctx.num_threads());
+
+        while !found.load(Ordering::Relaxed) {
+            let Some((start, count)) = cursor.claim(sizer.next_size(cursor.remaining())) else {
+                break;
+            };
+
+            let chunk_start = Instant::now();
+            let mut tried = 0;
+            for counter in start..start + count {
+                if found.load(Ordering::Relaxed) {
+                    break;
+                }
+                tried += 1;
+                // About half of the r values are not on the curve
+                let Some(sender) = deployment.sender_with(counter, fixed_hash) else {
+                    continue;
+                };
+                let address = match target {
+                    KeylessTarget::Sender => sender,
+                    KeylessTarget::Contract => get_create_address(&sender, 0),
+                };
+                if pattern.matches(&address) {
+                    found.store(true, Ordering::Relaxed);
+                    let mut result = result.lock().unwrap();
+                    if result.is_none() {
+                        *result = deployment.transaction(counter);
+                    }
+                    break;
+                }
+            }
+            checked.fetch_add(tried, Ordering::Relaxed);
+            sizer.record(tried, chunk_start.elapsed());
+        }
+    });
+
+    result.into_inner().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{address, bytes};
+
+    /// Arachnid's deterministic deployment proxy
+    fn deployment_proxy(variation: KeylessVariation) -> KeylessDeployment {
+        KeylessDeployment {
+            init_code: bytes!("604580600e600039806000f350fe7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe03601600081602082378035828234f58015156039578182fd5b8082525050506014600cf3"),
+            gas_price: 100_000_000_000,
+            gas_limit: 100_000,
+            variation,
+        }
+    }
+
+    #[test]
+    fn test_deterministic_deployment_proxy() {
+        let tx = deployment_proxy(KeylessVariation::R)
+            .transaction(0)
+            .unwrap();
+
+        assert_eq!(
+            tx.sender,
+            address!("3fab184622dc19b6109349b94811493bf2a45362")
+        );
+        assert_eq!(
+            tx.contract,
+            address!("4e59b44847b379578588920ca78fbf26c0b4956c")
+        );
+        assert_eq!(
+            tx.raw_transaction,
+            bytes!("f8a58085174876e800830186a08080b853604580600e600039806000f350fe7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe03601600081602082378035828234f58015156039578182fd5b8082525050506014600cf31ba02222222222222222222222222222222222222222222222222222222222222222a02222222222222222222222222222222222222222222222222222222222222222")
+        );
+    }
+
+    #[test]
+    fn test_search_keyless() {
+        for (variation, target) in [
+            (KeylessVariation::R, KeylessTarget::Contract),
+            (KeylessVariation::GasPrice, KeylessTarget::Sender),
+        ] {
+            let deployment = deployment_proxy(variation);
+            let pattern = AddressPattern::prefix("ab", false).unwrap();
+            let cursor = SaltCursor::new(0, 100_000);
+            let found = AtomicBool::new(false);
+            let checked = AtomicU64::new(0);
+
+            let tx =
+                search_keyless(&deployment, &pattern, target, &cursor, &found, &checked).unwrap();
+
+            let matched = match target {
+                KeylessTarget::Sender => tx.sender,
+                KeylessTarget::Contract => tx.contract,
+            };
+            assert!(pattern.matches(&matched));
+            assert_eq!(deployment.sender(tx.counter), Some(tx.sender));
+            assert_eq!(
+                deployment.sender_with(tx.counter, deployment.fixed_signature_hash()),
+                Some(tx.sender)
+            );
+            if variation == KeylessVariation::GasPrice {
+                assert_eq!(tx.gas_price, deployment.gas_price + tx.counter as u128);
+            }
+        }
+    }
+
+    #[test]
+    fn test_keyless_gas_price_overflow() {
+        let mut deployment = deployment_proxy(KeylessVariation::GasPrice);
+        deployment.gas_price = u128::MAX - 1;
+
+        // Counters 0 and 1 reach exactly u128::MAX
+        assert!(deployment.check_counters(2).is_ok());
+        assert!(deployment.check_counters(3).is_err());
+        assert!(deployment.sender(2).is_none());
+        assert!(deployment.transaction(2).is_none());
+
+        // Funding is computed in 256 bits, so huge gas prices still report the right amount
+        let tx = deployment.transaction(1).unwrap();
+        assert_eq!(
+            tx.funding(),
+            U256::from(u128::MAX) * U256::from(deployment.gas_limit)
+        );
+    }
+
+    #[test]
+    fn test_search_keyless_counts_tried() {
+        // One worker tries counters in order and stops at the match
+        let deployment = deployment_proxy(KeylessVariation::R);
+        let pattern = AddressPattern::prefix("ab", false).unwrap();
+        let cursor = SaltCursor::new(0, 100_000);
+        let found = AtomicBool::new(false);
+        let checked = AtomicU64::new(0);
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap();
+        let tx = pool
+            .install(|| {
+                search_keyless(
+                    &deployment,
+                    &pattern,
+                    KeylessTarget::Contract,
+                    &cursor,
+                    &found,
+                    &checked,
+                )
+            })
+            .unwrap();
+        assert_eq!(checked.load(Ordering::Relaxed), tx.counter + 1);
+    }
+}
@@ -1,13 +1,47 @@
+pub mod account;
 pub mod address;
+pub mod artifact;
+pub mod clone;
 pub mod config;
+pub mod createx;
+pub mod eoa;
+pub mod hook;
+pub mod init_code;
+pub mod keccak;
+pub mod keyless;
+pub mod link;
 pub mod logger;
 pub mod mining;
+pub mod mnemonic;
+pub mod nonce;
+pub mod pattern;
+pub mod safe;
+pub mod salt;
+pub mod scheduler;
 pub mod utils;
 
 pub use address::{
-    check_address_match, get_create2_address, get_create3_address, get_deployed_address,
+    check_address_match, get_create2_address, get_create3_address, get_create3_proxy_address,
+    get_create_address, get_deployed_address, get_zksync_create2_address,
+    get_zksync_create_address, AddressDeriver, CREATE3_PROXY_INITCODE_HASH,
 };
-pub use config::{Args, Commands, CommonArgs, Create2Args, Create3Args, MinerConfig, MiningMode};
+pub use artifact::Artifact;
+pub use config::{
+    AccountArgs, AccountPreset, Args, CloneArgs, CloneKind, CombineKeyArgs, Commands, CommonArgs,
+    Create2Args, Create3Args, Create3Flavor, CreateArgs, CreatexArgs, DecryptMnemonicArgs, EoaArgs,
+    EoaDeployerArgs, HookDeployment, KeylessArgs, KeylessTarget, KeylessVariation, MinerConfig,
+    MiningMode, MnemonicArgs, PatternArgs, SafeArgs, SaltRecipe, SaltRecipeKind, SaltSearchArgs,
+    ZksyncCreate2Args,
+};
+pub use createx::CREATEX_ADDRESS;
+pub use eoa::{search_keys, EoaMatch};
+pub use init_code::InitCode;
+pub use keccak::KeccakBackend;
+pub use link::LibraryLink;
 pub use logger::Logger;
 pub use mining::{process_batch, MinerResult};
+pub use nonce::{scan_nonces, scan_nonces_with, NonceMatch};
+pub use pattern::AddressPattern;
+pub use salt::SaltTemplate;
+pub use scheduler::{mine, SaltCursor};
 pub use utils::{compute_final_salt, format_number, parse_address, parse_bytes32, to_bytes32};
@@ -0,0 +1,175 @@
+use alloy::primitives::{keccak256, Address};
+use anyhow::Result;
+
+use crate::utils::parse_address;
+
+/// Length in hex characters of a library placeholder (the size of an address)
+const PLACEHOLDER_LEN: usize = 40;
+
+/// Deployed address of a library referenced by creation bytecode
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LibraryLink {
+    /// Library name, optionally fully qualified as `path/File.sol:Lib`
+    pub name: String,
+    pub address: Address,
+}
+
+impl LibraryLink {
+    /// Parse a `Lib=0x...` argument
+    pub fn parse(link: &str) -> Result<Self> {
+        let Some((name, address)) = link.split_once('=') else {
+            anyhow::bail!("Invalid library link '{}': expected Lib=0x...", link);
+        };
+        if name.is_empty() {
+            anyhow::bail!("Invalid library link '{}': missing library name", link);
+        }
+        Ok(Self {
+            name: name.to_string(),
+            address: parse_address(address)?,
+        })
+    }
+
+    /// Fully qualified names this link may stand for, given the libraries an artifact
+    /// references (`path:Lib`)
+    fn qualified_names(&self, references: &[String]) -> Vec<String> {
+        if self.name.contains(':') {
+            return vec![self.name.clone()];
+        }
+        references
+            .iter()
+            .filter(|reference| {
+                reference
+                    .rsplit_once(':')
+                    .is_some_and(|(_, name)| name == self.name)
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// `__$<first 17 bytes of keccak256(name)>$__` placeholder emitted by solc >= 0.5
+pub fn placeholder(qualified_name: &str) -> String {
+    let hash = alloy::hex::encode(keccak256(qualified_name.as_bytes()));
+    format!("__${}$__", &hash[..34])
+}
+
+/// `__<name>___` placeholder emitted by solc < 0.5, padded to the size of an address
+fn legacy_placeholder(name: &str) -> String {
+    let name: String = name.chars().take(PLACEHOLDER_LEN - 4).collect();
+    format!("__{:_<width$}", name, width = PLACEHOLDER_LEN - 2)
+}
+
+/// Replace library placeholders in hex `bytecode` with the linked addresses.
+///
+/// `references` lists the fully qualified libraries the artifact declares, so links can
+/// use bare library names. Unused links and placeholders left unresolved are errors,
+/// since the resulting init code hash would not match the deployment.
+pub fn link_bytecode(
+    bytecode: &str,
+    links: &[LibraryLink],
+    references: &[String],
+) -> Result<String> {
+    let mut linked = bytecode.to_string();
+
+    for link in links {
+        let address = alloy::hex::encode(link.address);
+        let mut placeholders: Vec<String> = link
+            .qualified_names(references)
+            .iter()
+            .flat_map(|name| [placeholder(name), legacy_placeholder(name)])
+            .collect();
+        placeholders.push(legacy_placeholder(&link.name));
+
+        let before = linked.clone();
+        for placeholder in &placeholders {
+            linked = linked.replace(placeholder, &address);
+        }
+        if linked == before {
+            anyhow::bail!(
+                "Library '{}' is not referenced by the bytecode (use the fully qualified name path:Lib if it has no artifact)",
+                link.name
+            );
+        }
+    }
+
+    if let Some(start) = linked.find("__") {
+        let found = linked[start..]
+            .chars()
+            .take(PLACEHOLDER_LEN)
+            .collect::<String>();
+        let library = references
+            .iter()
+            .find(|name| placeholder(name) == found)
+            .map_or(String::new(), |name| format!(" for library {}", name));
+        anyhow::bail!(
+            "Unresolved library placeholder {}{}; pass --link Lib=0x...",
+            found,
+            library
+        );
+    }
+    Ok(linked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::address;
+
+    const LIB: &str = "src/Math.sol:Math";
+
+    fn bytecode() -> String {
+        format!("0x6080{}6040{}52", placeholder(LIB), placeholder(LIB))
+    }
+
+    #[test]
+    fn test_link_placeholders() {
+        let lib = address!("1111111111111111111111111111111111111111");
+        let expected = format!("0x6080{}6040{}52", "11".repeat(20), "11".repeat(20));
+
+        // Fully qualified name, no artifact references needed
+        let link = LibraryLink::parse(&format!("{}=0x{}", LIB, "11".repeat(20))).unwrap();
+        assert_eq!(link_bytecode(&bytecode(), &[link], &[]).unwrap(), expected);
+
+        // Bare name resolved through the artifact's link references
+        let link = LibraryLink {
+            name: "Math".to_string(),
+            address: lib,
+        };
+        assert_eq!(
+            link_bytecode(&bytecode(), &[link], &[LIB.to_string()]).unwrap(),
+            expected
+        );
+
+        // Legacy solc placeholders
+        let legacy = format!("60{}60", legacy_placeholder("Math"));
+        assert_eq!(legacy.len(), 44);
+        let link = LibraryLink {
+            name: "Math".to_string(),
+            address: lib,
+        };
+        assert_eq!(
+            link_bytecode(&legacy, &[link], &[]).unwrap(),
+            format!("60{}60", "11".repeat(20))
+        );
+    }
+
+    #[test]
+    fn test_link_errors() {
+        let error = link_bytecode(&bytecode(), &[], &[LIB.to_string()]).unwrap_err();
+        assert!(error.to_string().contains(LIB));
+
+        let unused = LibraryLink {
+            name: "Other".to_string(),
+            address: Address::ZERO,
+        };
+        assert!(link_bytecode(&bytecode(), &[unused], &[LIB.to_string()]).is_err());
+
+        // Non-ASCII input after a placeholder start is an error, not a panic
+        let bytecode = format!("0x60__a{}", "é".repeat(30));
+        assert!(link_bytecode(&bytecode, &[], &[]).is_err());
+
+        assert!(LibraryLink::parse("Math").is_err());
+        assert!(LibraryLink::parse("=0x1111111111111111111111111111111111111111").is_err());
+        assert!(LibraryLink::parse("Math=0x11").is_err());
+    }
+}
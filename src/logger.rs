@@ -44,9 +44,13 @@ impl Logger {
         println!("{}", message.bright_green());
     }
 
-    pub fn no_result() {
+    /// `unit` names one searched item (salt, nonce, key, ...)
+    pub fn no_result(unit: &str) {
         println!();
-        println!("{}", "💔 No matching salt found".bright_red().bold());
+        println!(
+            "{}",
+            format!("💔 No matching {} found", unit).bright_red().bold()
+        );
     }
 
     pub fn separator() {
@@ -66,10 +70,10 @@ impl Logger {
         pb
     }
 
-    pub fn print_metrics(checked: u64, rate: f64, elapsed: f64) {
+    pub fn print_metrics(checked: u64, rate: f64, elapsed: f64, unit: &str) {
         Self::separator();
         Self::info("Total Checked", &crate::format_number(checked));
-        Self::info("Average Rate", &format!("{:.0} salts/sec", rate));
+        Self::info("Average Rate", &format!("{:.0} {}s/sec", rate, unit));
         Self::info("Time Elapsed", &format!("{:.2}s", elapsed));
         Self::separator();
     }
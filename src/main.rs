@@ -1,153 +1,609 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Parser;
 use indicatif::ProgressBar;
-use rayon::prelude::*;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, Instant};
 
+use alloy::primitives::{keccak256, Address, Bytes, B256};
+use alloy::signers::local::PrivateKeySigner;
+use ca_miner::account::SmartAccount;
+use ca_miner::clone::clone_init_code;
+use ca_miner::createx;
+use ca_miner::eoa::{combine_keys, parse_public_key, parse_secret_key, write_keystore};
+use ca_miner::hook::{hook_flag_names, hook_pattern, parse_hook_flags};
+use ca_miner::init_code::{encode_constructor_args, InitCodeVariation, MAX_INIT_CODE_SIZE};
+use ca_miner::keyless::{search_keyless, KeylessDeployment, KEYLESS_SIGNATURE_VALUE};
+use ca_miner::mnemonic::{
+    derive_address, read_mnemonic_keystore, search_mnemonics, write_mnemonic,
+    write_mnemonic_keystore,
+};
+use ca_miner::safe::{proxy_init_code, SafeSetup};
 use ca_miner::{
-    format_number, parse_address, parse_bytes32, process_batch, Args, Commands, CommonArgs,
-    Create2Args, Create3Args, Logger, MinerConfig, MinerResult, MiningMode,
+    format_number, get_create3_proxy_address, get_create_address, get_zksync_create_address, mine,
+    parse_address, parse_bytes32, scan_nonces_with, search_keys, AccountArgs, Args, Artifact,
+    CloneArgs, CloneKind, CombineKeyArgs, Commands, CommonArgs, Create2Args, Create3Args,
+    CreateArgs, CreatexArgs, DecryptMnemonicArgs, EoaArgs, EoaMatch, HookDeployment, InitCode,
+    KeccakBackend, KeylessArgs, KeylessTarget, LibraryLink, Logger, MinerConfig, MinerResult,
+    MiningMode, MnemonicArgs, PatternArgs, SafeArgs, SaltCursor, SaltRecipe, SaltTemplate,
+    ZksyncCreate2Args, CREATE3_PROXY_INITCODE_HASH,
 };
+use k256::elliptic_curve::zeroize::Zeroizing;
+use k256::SecretKey;
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
     match args.command {
-        Commands::Create2(create2_args) => {
-            let config = build_create2_config(&create2_args)?;
-            print_startup_info_create2(&create2_args, &config);
-            run_mining(&create2_args, config)
+        Commands::Create2(create2_args) => run_create2(&create2_args, None),
+        Commands::Create3(create3_args) => run_create3(&create3_args, None),
+        Commands::Create(create_args) => run_nonce_scan(&create_args),
+        Commands::Safe(safe_args) => {
+            let (config, setup) = build_safe_config(&safe_args)?;
+            print_startup_info_safe(&safe_args, &config, &setup);
+            run_mining(&safe_args, config)
+        }
+        Commands::Account(account_args) => {
+            let (config, account) = build_account_config(&account_args)?;
+            print_startup_info_account(&account_args, &config, &account);
+            run_mining(&account_args, config)
+        }
+        Commands::Clone(clone_args) => {
+            let (config, init_code) = build_clone_config(&clone_args)?;
+            print_startup_info_clone(&clone_args, &config, &init_code);
+            run_mining(&clone_args, config)
+        }
+        Commands::V4Hook(hook_args) => {
+            let flags = parse_hook_flags(&hook_args.flags)?;
+            match &hook_args.deployment {
+                HookDeployment::Create2(create2_args) => run_create2(create2_args, Some(flags)),
+                HookDeployment::Create3(create3_args) => run_create3(create3_args, Some(flags)),
+            }
         }
-        Commands::Create3(create3_args) => {
-            let config = build_create3_config(&create3_args)?;
-            print_startup_info_create3(&create3_args, &config);
-            run_mining(&create3_args, config)
+        Commands::ZksyncCreate2(zksync_args) => {
+            let config = build_zksync_create2_config(&zksync_args)?;
+            print_startup_info_zksync_create2(&zksync_args, &config);
+            run_mining(&zksync_args, config)
+        }
+        Commands::Eoa(eoa_args) => run_key_search(&eoa_args, None),
+        Commands::EoaDeployer(deployer_args) => {
+            run_key_search(&deployer_args.eoa, Some(deployer_args.nonce))
+        }
+        Commands::Mnemonic(mnemonic_args) => run_mnemonic_search(&mnemonic_args),
+        Commands::DecryptMnemonic(decrypt_args) => run_decrypt_mnemonic(&decrypt_args),
+        Commands::Keyless(keyless_args) => run_keyless_search(&keyless_args),
+        Commands::CombineKey(combine_args) => run_combine_key(&combine_args),
+        Commands::Createx(createx_args) => {
+            let config = build_createx_config(&createx_args)?;
+            print_startup_info_createx(&createx_args, &config);
+            run_mining(&createx_args, config)
         }
     }
 }
 
-fn build_create2_config(args: &Create2Args) -> Result<MinerConfig> {
-    let factory_address = parse_address(args.factory())?;
-
-    // For CREATE2, expect bytecode hash (32 bytes)
-    let url_or_bytecode_bytes = if args.bytecode_hash.starts_with("0x") {
-        parse_bytes32(&args.bytecode_hash)?.to_vec()
-    } else {
-        anyhow::bail!("For CREATE2 mode, provide bytecode hash as hex (0x...)")
+/// Mine a CREATE2 address, additionally requiring v4 hook permission bits when `hook_flags` is set
+fn run_create2(args: &Create2Args, hook_flags: Option<u16>) -> Result<()> {
+    let links = args
+        .link
+        .iter()
+        .map(|link| LibraryLink::parse(link))
+        .collect::<Result<Vec<_>>>()?;
+    let artifact = args
+        .artifact
+        .as_deref()
+        .map(|path| Artifact::load(path, &links))
+        .transpose()?;
+    let mut init_code = match &artifact {
+        Some(artifact) => Some(artifact.init_code.clone()),
+        None => args
+            .init_code
+            .as_deref()
+            .map(|source| InitCode::load(source, &links))
+            .transpose()?,
     };
+    if let Some(signature) = &args.constructor {
+        let encoded = encode_constructor_args(signature, &args.constructor_args)?;
+        init_code = match init_code {
+            Some(init_code) => Some(init_code.with_constructor_args(encoded)),
+            None => anyhow::bail!("--constructor requires --init-code or --artifact"),
+        };
+    }
+    let mut config = build_create2_config(args, init_code.as_ref())?;
+    if let Some(flags) = hook_flags {
+        config.pattern = config.pattern.and(&hook_pattern(flags))?;
+    }
+    print_startup_info_create2(
+        args,
+        &config,
+        init_code.as_ref(),
+        artifact.as_ref(),
+        hook_flags,
+    );
+    run_mining(args, config)
+}
 
-    // Validate argument combinations
-    if args.postfix() && args.postfix_pattern().is_some() {
-        anyhow::bail!(
-            "Cannot use both --postfix and --postfix-pattern flags. Use --postfix-pattern for dual matching."
+fn run_create3(args: &Create3Args, hook_flags: Option<u16>) -> Result<()> {
+    let mut config = build_create3_config(args)?;
+    if let Some(flags) = hook_flags {
+        config.pattern = config.pattern.and(&hook_pattern(flags))?;
+    }
+    print_startup_info_create3(args, &config, hook_flags);
+    run_mining(args, config)
+}
+
+fn print_hook_flags(hook_flags: Option<u16>) {
+    if let Some(flags) = hook_flags {
+        Logger::info(
+            "Hook Flags",
+            &format!("{} (0x{:04x})", hook_flag_names(flags).join(", "), flags),
         );
     }
+}
 
-    let (prefix_bytes, prefix_len) = process_pattern_bytes(args.prefix(), args.case_sensitive());
-    let (postfix_bytes, postfix_len) = if let Some(postfix_pattern) = args.postfix_pattern() {
-        process_pattern_bytes(postfix_pattern, args.case_sensitive())
-    } else {
-        (Vec::new(), 0)
+fn build_create2_config(args: &Create2Args, init_code: Option<&InitCode>) -> Result<MinerConfig> {
+    let factory_address = parse_address(args.factory())?;
+
+    // For CREATE2, expect bytecode hash (32 bytes) or init code to hash
+    let url_or_bytecode_bytes = match (&args.bytecode_hash, init_code) {
+        (_, Some(init_code)) => init_code.hash().to_vec(),
+        (Some(hash), None) if hash.starts_with("0x") => parse_bytes32(hash)?.to_vec(),
+        (Some(_), None) => {
+            anyhow::bail!("For CREATE2 mode, provide bytecode hash as hex (0x...)")
+        }
+        (None, None) => {
+            anyhow::bail!("Provide either BYTECODE_HASH, --init-code or --artifact")
+        }
     };
 
-    let postfix_only = args.postfix() && args.postfix_pattern().is_none();
-    let dual_matching = args.postfix_pattern().is_some();
+    let pattern = args.pattern().compile()?;
+
+    // With a fixed salt, the counter lives in the init code instead
+    let (mode, salt_template) = match &args.vary_init_code {
+        Some(range) => {
+            let Some(init_code) = init_code else {
+                anyhow::bail!("--vary-init-code requires --init-code or --artifact");
+            };
+            let salt = args
+                .salt
+                .as_deref()
+                .map(parse_bytes32)
+                .transpose()?
+                .unwrap_or_default();
+            let variation = InitCodeVariation::new(init_code.bytes.clone(), range, salt)?;
+            let salt_template = variation
+                .salt_template()
+                .with_start(&args.search().start_salt)?;
+            (MiningMode::InitCode(variation), salt_template)
+        }
+        None => (MiningMode::Create2, build_salt_template(args)?),
+    };
 
     Ok(MinerConfig {
         factory_address,
         url_or_bytecode_bytes,
-        prefix_bytes,
-        prefix_len,
-        postfix_bytes,
-        postfix_len,
-        mode: MiningMode::Create2,
-        case_sensitive: args.case_sensitive(),
-        postfix_only,
-        dual_matching,
+        pattern,
+        mode,
+        case_sensitive: args.pattern().case_sensitive,
+        postfix_only: args.pattern().postfix_only(),
+        dual_matching: args.pattern().dual_matching(),
+        salt_template,
+        salt_recipe: SaltRecipe::Raw,
+        proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
     })
 }
 
 fn build_create3_config(args: &Create3Args) -> Result<MinerConfig> {
     let factory_address = parse_address(args.factory())?;
 
-    // For CREATE3, use URL as bytes
-    let url_or_bytecode_bytes = args.url.as_bytes().to_vec();
+    // The flavor decides how the raw salt is hashed and which proxy is deployed
+    let deployer = args.deployer.as_deref().map(parse_address).transpose()?;
+    let proxy_hash = args.proxy_hash.as_deref().map(parse_bytes32).transpose()?;
+    let salt_recipe = args.create3_flavor.salt_recipe(
+        args.url.as_deref(),
+        deployer.as_ref(),
+        args.salt_recipe,
+    )?;
+    let proxy_init_code_hash = args.create3_flavor.proxy_init_code_hash(proxy_hash)?;
+
+    let pattern = args.pattern().compile()?;
 
-    // Validate argument combinations
-    if args.postfix() && args.postfix_pattern().is_some() {
+    Ok(MinerConfig {
+        factory_address,
+        url_or_bytecode_bytes: Vec::new(),
+        pattern,
+        mode: MiningMode::Create3,
+        case_sensitive: args.pattern().case_sensitive,
+        postfix_only: args.pattern().postfix_only(),
+        dual_matching: args.pattern().dual_matching(),
+        salt_template: build_salt_template(args)?,
+        salt_recipe,
+        proxy_init_code_hash,
+    })
+}
+
+fn build_createx_config(args: &CreatexArgs) -> Result<MinerConfig> {
+    let factory_address = parse_address(args.factory())?;
+    let caller = args.caller.as_deref().map(parse_address).transpose()?;
+
+    let (mode, url_or_bytecode_bytes) = match (&args.bytecode_hash, args.create3) {
+        (Some(hash), false) => (MiningMode::Create2, parse_bytes32(hash)?.to_vec()),
+        (None, true) => (MiningMode::Create3, Vec::new()),
+        _ => anyhow::bail!("Provide either a bytecode hash (deployCreate2) or --create3"),
+    };
+
+    let pattern = args.pattern().compile()?;
+    if args.permissioned && caller.is_none() {
+        anyhow::bail!("--permissioned requires --caller");
+    }
+    if args.search().salt_template.is_some() && (args.permissioned || args.cross_chain_protection) {
         anyhow::bail!(
-            "Cannot use both --postfix and --postfix-pattern flags. Use --postfix-pattern for dual matching."
+            "--salt-template already fixes the sender and flag bytes; drop --permissioned/--cross-chain-protection"
         );
     }
 
-    let (prefix_bytes, prefix_len) = process_pattern_bytes(args.prefix(), args.case_sensitive());
-    let (postfix_bytes, postfix_len) = if let Some(postfix_pattern) = args.postfix_pattern() {
-        process_pattern_bytes(postfix_pattern, args.case_sensitive())
-    } else {
-        (Vec::new(), 0)
-    };
-
-    let postfix_only = args.postfix() && args.postfix_pattern().is_none();
-    let dual_matching = args.postfix_pattern().is_some();
+    let default_template = createx::salt_template(
+        caller.as_ref().filter(|_| args.permissioned),
+        args.cross_chain_protection,
+    );
+    let salt_template = build_salt_template_or(args, default_template)?;
+    let salt_recipe = createx::guard_recipe(&salt_template, caller.as_ref(), args.chain_id)?;
 
     Ok(MinerConfig {
         factory_address,
         url_or_bytecode_bytes,
-        prefix_bytes,
-        prefix_len,
-        postfix_bytes,
-        postfix_len,
-        mode: MiningMode::Create3,
-        case_sensitive: args.case_sensitive(),
-        postfix_only,
-        dual_matching,
+        pattern,
+        mode,
+        case_sensitive: args.pattern().case_sensitive,
+        postfix_only: args.pattern().postfix_only(),
+        dual_matching: args.pattern().dual_matching(),
+        salt_template,
+        salt_recipe,
+        proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
     })
 }
 
-fn process_pattern_bytes(pattern: &str, case_sensitive: bool) -> (Vec<u8>, usize) {
-    if case_sensitive {
-        // For case-sensitive mode, preserve original case
-        let pattern = pattern.strip_prefix("0x").unwrap_or(pattern);
-        (pattern.as_bytes().to_vec(), pattern.len())
-    } else {
-        // For case-insensitive mode, convert to lowercase
-        let pattern = pattern.strip_prefix("0x").unwrap_or(pattern).to_lowercase();
-        (pattern.as_bytes().to_vec(), pattern.len())
+fn build_zksync_create2_config(args: &ZksyncCreate2Args) -> Result<MinerConfig> {
+    let bytecode_hash = parse_bytes32(&args.bytecode_hash)?;
+    let constructor_input = alloy::hex::decode(&args.constructor_input)
+        .map_err(|e| anyhow::anyhow!("Invalid --constructor-input: {}", e))?;
+
+    Ok(MinerConfig {
+        mode: MiningMode::ZksyncCreate2 {
+            constructor_input_hash: keccak256(&constructor_input),
+        },
+        ..miner_config(args, bytecode_hash, SaltRecipe::Raw)?
+    })
+}
+
+fn build_clone_config(args: &CloneArgs) -> Result<(MinerConfig, Vec<u8>)> {
+    let implementation = parse_address(&args.implementation)?;
+    let immutable_args = args
+        .args
+        .as_deref()
+        .map(alloy::hex::decode)
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("Invalid --args: {}", e))?;
+    let init_code = clone_init_code(args.kind, &implementation, immutable_args.as_deref())?;
+
+    let config = miner_config(args, keccak256(&init_code), SaltRecipe::Raw)?;
+    Ok((config, init_code))
+}
+
+fn build_account_config(args: &AccountArgs) -> Result<(MinerConfig, SmartAccount)> {
+    let account = SmartAccount {
+        preset: args.preset,
+        implementation: parse_address(&args.implementation)?,
+        owners: args
+            .owner
+            .iter()
+            .map(|owner| parse_address(owner))
+            .collect::<Result<Vec<_>>>()?,
+        init_call: match &args.init_call {
+            Some(data) => alloy::hex::decode(data)
+                .map_err(|e| anyhow::anyhow!("Invalid --init-call: {}", e))?
+                .into(),
+            None => Bytes::new(),
+        },
+        proxy_creation_code: match &args.proxy_creation_code {
+            Some(source) => InitCode::load(source, &[])?.bytes.into(),
+            None => Bytes::new(),
+        },
+    };
+    account.validate()?;
+
+    let config = miner_config(args, keccak256(account.init_code()), account.salt_recipe())?;
+    Ok((config, account))
+}
+
+fn build_safe_config(args: &SafeArgs) -> Result<(MinerConfig, SafeSetup)> {
+    let singleton = parse_address(&args.singleton)?;
+    let proxy_creation_code = InitCode::load(&args.proxy_creation_code, &[])?;
+
+    let owners = args
+        .owners
+        .iter()
+        .map(|owner| parse_address(owner))
+        .collect::<Result<Vec<_>>>()?;
+    for (i, owner) in owners.iter().enumerate() {
+        if owner.is_zero() || owners[..i].contains(owner) {
+            anyhow::bail!("Invalid or duplicate Safe owner: {}", owner);
+        }
     }
+    if args.threshold == 0 || args.threshold > owners.len() as u64 {
+        anyhow::bail!(
+            "--threshold must be between 1 and the number of owners ({})",
+            owners.len()
+        );
+    }
+    let setup = SafeSetup {
+        owners,
+        threshold: args.threshold,
+        to: args
+            .setup_to
+            .as_deref()
+            .map(parse_address)
+            .transpose()?
+            .unwrap_or_default(),
+        data: alloy::hex::decode(&args.setup_data)
+            .map_err(|e| anyhow::anyhow!("Invalid --setup-data: {}", e))?
+            .into(),
+        fallback_handler: args
+            .fallback_handler
+            .as_deref()
+            .map(parse_address)
+            .transpose()?
+            .unwrap_or_default(),
+    };
+
+    let init_code = proxy_init_code(&proxy_creation_code.bytes, &singleton);
+    let config = miner_config(
+        args,
+        keccak256(&init_code),
+        SaltRecipe::initializer(&setup.initializer()),
+    )?;
+    Ok((config, setup))
+}
+
+/// CREATE2 config for `init_code_hash` deployed by the factory, with the mined salt turned
+/// into the CREATE2 salt by `salt_recipe`
+fn miner_config<T: CommonArgs>(
+    args: &T,
+    init_code_hash: B256,
+    salt_recipe: SaltRecipe,
+) -> Result<MinerConfig> {
+    Ok(MinerConfig {
+        factory_address: parse_address(args.factory())?,
+        url_or_bytecode_bytes: init_code_hash.to_vec(),
+        pattern: args.pattern().compile()?,
+        mode: MiningMode::Create2,
+        case_sensitive: args.pattern().case_sensitive,
+        postfix_only: args.pattern().postfix_only(),
+        dual_matching: args.pattern().dual_matching(),
+        salt_template: build_salt_template(args)?,
+        salt_recipe,
+        proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
+    })
+}
+
+fn build_salt_template<T: CommonArgs>(args: &T) -> Result<SaltTemplate> {
+    build_salt_template_or(args, SaltTemplate::default())
+}
+
+fn build_salt_template_or<T: CommonArgs>(args: &T, default: SaltTemplate) -> Result<SaltTemplate> {
+    let template = match &args.search().salt_template {
+        Some(template) => SaltTemplate::parse(template)?,
+        None => default,
+    };
+    template.with_start(&args.search().start_salt)
 }
 
-fn print_startup_info_create2(args: &Create2Args, config: &MinerConfig) {
+fn print_startup_info_create2(
+    args: &Create2Args,
+    config: &MinerConfig,
+    init_code: Option<&InitCode>,
+    artifact: Option<&Artifact>,
+    hook_flags: Option<u16>,
+) {
     Logger::header("High-Performance CREATE2 Salt Miner");
     Logger::info("Mode", "CREATE2");
     Logger::info("Factory", args.factory());
-    Logger::info("Bytecode Hash", &args.bytecode_hash);
+    if let Some(artifact) = artifact {
+        Logger::info(
+            "Artifact",
+            &format!("{} ({})", artifact.init_code.source, artifact.format),
+        );
+        if let Some(contract) = &artifact.contract {
+            Logger::info("Contract", contract);
+        }
+        if let Some(compiler) = &artifact.compiler {
+            Logger::info("Compiler", compiler);
+        }
+    }
+    if let Some(init_code) = init_code {
+        Logger::info(
+            "Init Code",
+            &format!(
+                "{} bytes from {}",
+                format_number(init_code.bytes.len() as u64),
+                init_code.source
+            ),
+        );
+    }
+    for link in &args.link {
+        Logger::info("Library", link);
+    }
+    if let Some(init_code) = init_code.filter(|code| !code.constructor_args.is_empty()) {
+        Logger::info(
+            "Constructor Args",
+            &format!("0x{}", alloy::hex::encode(&init_code.constructor_args)),
+        );
+    }
+    Logger::info(
+        "Bytecode Hash",
+        &B256::from_slice(&config.url_or_bytecode_bytes).to_string(),
+    );
+    if let MiningMode::InitCode(variation) = &config.mode {
+        Logger::info(
+            "Mined Init Code Bytes",
+            &format!(
+                "{}..{} ({} bytes)",
+                variation.offset,
+                variation.offset + variation.len,
+                variation.len
+            ),
+        );
+        Logger::info("Fixed Salt", &variation.salt.to_string());
+    }
+    if init_code.is_some_and(InitCode::looks_like_hash) {
+        Logger::warning(
+            "Init code is exactly 32 bytes, which looks like a hash; pass a hash as BYTECODE_HASH instead",
+        );
+    }
+    if init_code.is_some_and(InitCode::is_oversized) {
+        Logger::warning(&format!(
+            "Init code exceeds the EIP-3860 limit of {} bytes",
+            format_number(MAX_INIT_CODE_SIZE as u64)
+        ));
+    }
+    print_hook_flags(hook_flags);
     print_common_startup_info(args, config);
 }
 
-fn print_startup_info_create3(args: &Create3Args, config: &MinerConfig) {
+fn print_startup_info_create3(args: &Create3Args, config: &MinerConfig, hook_flags: Option<u16>) {
     Logger::header("High-Performance CREATE3 Salt Miner");
     Logger::info("Mode", "CREATE3");
     Logger::info("Factory", args.factory());
-    Logger::info("URL", &args.url);
+    Logger::info("Flavor", &format!("{:?}", args.create3_flavor));
+    if let Some(url) = &args.url {
+        Logger::info("URL", url);
+    }
+    if let Some(deployer) = &args.deployer {
+        Logger::info("Deployer", deployer);
+    }
+    Logger::info("Proxy Hash", &config.proxy_init_code_hash.to_string());
+    print_hook_flags(hook_flags);
+    print_common_startup_info(args, config);
+}
+
+fn print_startup_info_createx(args: &CreatexArgs, config: &MinerConfig) {
+    Logger::header("High-Performance CreateX Salt Miner");
+    let mode = match config.mode {
+        MiningMode::Create3 => "CreateX deployCreate3",
+        _ => "CreateX deployCreate2",
+    };
+    Logger::info("Mode", mode);
+    Logger::info("Factory", args.factory());
+    if let Some(hash) = &args.bytecode_hash {
+        Logger::info("Bytecode Hash", hash);
+    }
+    if let Some(caller) = &args.caller {
+        Logger::info("Caller", caller);
+    }
+    if let Some(chain_id) = args.chain_id {
+        Logger::info("Chain ID", &chain_id.to_string());
+    }
+    print_common_startup_info(args, config);
+}
+
+fn print_startup_info_clone(args: &CloneArgs, config: &MinerConfig, init_code: &[u8]) {
+    Logger::header("High-Performance Clone Salt Miner");
+    Logger::info(
+        "Mode",
+        match (args.kind, &args.args) {
+            (CloneKind::Erc1167, None) => "ERC-1167 minimal proxy",
+            (CloneKind::Erc1167, Some(_)) => "ERC-1167 minimal proxy with immutable args",
+            (CloneKind::Erc1967, None) => "Solady ERC-1967 proxy",
+            (CloneKind::Erc1967, Some(_)) => "Solady ERC-1967 proxy with immutable args",
+        },
+    );
+    Logger::info("Deployer", args.factory());
+    Logger::info("Implementation", &args.implementation);
+    Logger::info("Init Code", &format!("0x{}", alloy::hex::encode(init_code)));
+    Logger::info(
+        "Init Code Hash",
+        &B256::from_slice(&config.url_or_bytecode_bytes).to_string(),
+    );
+    print_common_startup_info(args, config);
+}
+
+fn print_startup_info_account(args: &AccountArgs, config: &MinerConfig, account: &SmartAccount) {
+    Logger::header("High-Performance Smart Account Salt Miner");
+    Logger::info(
+        "Mode",
+        &format!("ERC-4337 account ({})", args.preset.name()),
+    );
+    Logger::info("Factory", args.factory());
+    Logger::info("Implementation", &account.implementation.to_string());
+    for owner in &account.owners {
+        Logger::info("Owner", &owner.to_string());
+    }
+    if !account.init_call.is_empty() {
+        Logger::info("Init Call", &account.init_call.to_string());
+    }
+    Logger::info(
+        "Account Init Code Hash",
+        &B256::from_slice(&config.url_or_bytecode_bytes).to_string(),
+    );
+    print_common_startup_info(args, config);
+}
+
+fn print_startup_info_safe(args: &SafeArgs, config: &MinerConfig, setup: &SafeSetup) {
+    Logger::header("High-Performance Safe saltNonce Miner");
+    Logger::info("Mode", "SafeProxyFactory.createProxyWithNonce");
+    Logger::info("Factory", args.factory());
+    Logger::info("Singleton", &args.singleton);
+    for owner in &setup.owners {
+        Logger::info("Owner", &owner.to_string());
+    }
+    Logger::info(
+        "Threshold",
+        &format!("{} of {}", setup.threshold, setup.owners.len()),
+    );
+    Logger::info("Fallback Handler", &setup.fallback_handler.to_string());
+    Logger::info(
+        "Initializer",
+        &format!("0x{}", alloy::hex::encode(setup.initializer())),
+    );
+    Logger::info(
+        "Proxy Init Code Hash",
+        &B256::from_slice(&config.url_or_bytecode_bytes).to_string(),
+    );
+    print_common_startup_info(args, config);
+}
+
+fn print_startup_info_zksync_create2(args: &ZksyncCreate2Args, config: &MinerConfig) {
+    Logger::header("High-Performance zkSync CREATE2 Salt Miner");
+    Logger::info("Mode", "zkSync Era CREATE2");
+    Logger::info("Sender", args.factory());
+    Logger::info("Bytecode Hash", &args.bytecode_hash);
+    if let MiningMode::ZksyncCreate2 {
+        constructor_input_hash,
+    } = &config.mode
+    {
+        Logger::info(
+            "Constructor Input Hash",
+            &constructor_input_hash.to_string(),
+        );
+    }
     print_common_startup_info(args, config);
 }
 
 fn print_common_startup_info<T: CommonArgs>(args: &T, config: &MinerConfig) {
     // Display pattern information
-    let case_mode = if args.case_sensitive() {
+    let pattern = args.pattern();
+    let case_mode = if pattern.case_sensitive {
         "case-sensitive (EIP-55)"
     } else {
         "case-insensitive"
     };
 
-    let display_prefix = get_display_pattern(args.prefix(), args.case_sensitive());
-    let display_postfix = args
-        .postfix_pattern()
+    let display_prefix = get_display_pattern(&pattern.prefix, pattern.case_sensitive);
+    let display_postfix = pattern
+        .postfix_pattern
         .as_ref()
-        .map(|p| get_display_pattern(p, args.case_sensitive()));
+        .map(|p| get_display_pattern(p, pattern.case_sensitive));
 
     if config.dual_matching {
         Logger::info("Prefix", &format!("0x{} ({})", display_prefix, case_mode));
@@ -162,14 +618,38 @@ fn print_common_startup_info<T: CommonArgs>(args: &T, config: &MinerConfig) {
         Logger::info("Prefix", &format!("0x{} ({})", display_prefix, case_mode));
     }
 
-    if args.random() {
+    log_bit_constraints(pattern);
+
+    Logger::info(
+        "Salt Template",
+        &format!(
+            "{} ({} mined bits)",
+            config.salt_template,
+            config.salt_template.width()
+        ),
+    );
+    if args.search().random {
         Logger::info("Salt Mode", "Random generation");
     } else {
-        Logger::info("Starting Salt", &args.start_salt().to_string());
+        Logger::info(
+            "Starting Salt",
+            &config.salt_template.start_salt().to_string(),
+        );
     }
-    Logger::info("Max Iterations", &format_number(args.max_iterations()));
-    Logger::info("Batch Size", &format_number(args.batch_size()));
+    Logger::info(
+        "Max Iterations",
+        &format_number(args.search().max_iterations),
+    );
+    Logger::info(
+        "Initial Batch Size",
+        &format_number(args.search().batch_size),
+    );
     Logger::info("CPU Cores", &rayon::current_num_threads().to_string());
+    let backend = KeccakBackend::detect();
+    Logger::info(
+        "Keccak Backend",
+        &format!("{} ({} lanes)", backend.name(), backend.lanes()),
+    );
 
     Logger::separator();
 }
@@ -184,55 +664,567 @@ fn get_display_pattern(pattern: &str, case_sensitive: bool) -> String {
 }
 
 fn run_mining<T: CommonArgs>(args: &T, config: MinerConfig) -> Result<()> {
+    let search = args.search();
+
+    // Workers claim chunks lazily from a shared cursor instead of a precomputed batch list.
+    // Sequential runs never need more iterations than the mined region has salts.
+    let iterations = match config.salt_template.capacity() {
+        Some(capacity) if !search.random => search.max_iterations.min(capacity),
+        _ => search.max_iterations,
+    };
+    let cursor = SaltCursor::new(0, iterations);
+
+    // Process chunks in parallel on every Rayon thread
+    let (result, stats) = run_search("Mining salts...", "salt", |found, total_checked| {
+        mine(
+            &config,
+            &cursor,
+            search.batch_size,
+            search.random,
+            found,
+            total_checked,
+        )
+    });
+
+    display_results(
+        result,
+        &stats,
+        &config,
+        args.raw_salt_label(),
+        args.final_salt_label(),
+    );
+
+    Ok(())
+}
+
+fn run_nonce_scan(args: &CreateArgs) -> Result<()> {
+    let deployer = parse_address(&args.deployer)?;
+
+    let pattern = args.pattern.compile()?;
+
+    Logger::header("CREATE Nonce Scanner");
+    Logger::info(
+        "Mode",
+        if args.zksync {
+            "zkSync Era CREATE"
+        } else {
+            "CREATE"
+        },
+    );
+    Logger::info("Deployer", &deployer.to_string());
+    Logger::info("Pattern", &pattern_summary(&args.pattern));
+    log_bit_constraints(&args.pattern);
+    Logger::info(
+        "Nonces",
+        &format!(
+            "{}..{}",
+            args.start_nonce,
+            args.start_nonce.saturating_add(args.max_nonces)
+        ),
+    );
+    Logger::info("Max Matches", &args.max_matches.to_string());
+    Logger::info("CPU Cores", &rayon::current_num_threads().to_string());
+    Logger::separator();
+
+    let (matches, stats) = run_search("Scanning nonces...", "nonce", |_, total_checked| {
+        let stop = AtomicBool::new(false);
+        scan_nonces_with(
+            |nonce| {
+                if args.zksync {
+                    get_zksync_create_address(&deployer, nonce)
+                } else {
+                    get_create_address(&deployer, nonce)
+                }
+            },
+            &pattern,
+            args.start_nonce,
+            args.max_nonces,
+            args.max_matches,
+            total_checked,
+            &stop,
+        )
+    });
+
+    if matches.is_empty() {
+        Logger::no_result(stats.unit);
+    } else {
+        Logger::found_result(&format!("{} matching nonce(s)", matches.len()));
+        Logger::separator();
+        for found in &matches {
+            let address = if args.pattern.case_sensitive {
+                found.address.to_checksum(None)
+            } else {
+                found.address.to_string()
+            };
+            Logger::info(
+                &format!("Nonce {}", found.nonce),
+                &format!(
+                    "{} (burn {} nonce(s) first)",
+                    address,
+                    format_number(found.nonce - args.start_nonce)
+                ),
+            );
+        }
+    }
+
+    stats.print();
+    Ok(())
+}
+
+fn log_bit_constraints(pattern: &PatternArgs) {
+    for (mask, value) in pattern.mask.iter().zip(&pattern.value) {
+        Logger::info(
+            "Bit Constraint",
+            &format!("address & {} == {}", mask, value),
+        );
+    }
+}
+
+fn pattern_summary(pattern: &PatternArgs) -> String {
+    let case_mode = if pattern.case_sensitive {
+        "case-sensitive (EIP-55)"
+    } else {
+        "case-insensitive"
+    };
+    let prefix = get_display_pattern(&pattern.prefix, pattern.case_sensitive);
+    match &pattern.postfix_pattern {
+        Some(postfix) => format!(
+            "0x{}...{} ({})",
+            prefix,
+            get_display_pattern(postfix, pattern.case_sensitive),
+            case_mode
+        ),
+        None if pattern.postfix => format!("0x...{} ({})", prefix, case_mode),
+        None => format!("0x{}... ({})", prefix, case_mode),
+    }
+}
+
+fn run_key_search(args: &EoaArgs, nonce: Option<u64>) -> Result<()> {
+    let pattern = args.pattern.compile()?;
+
+    let public_key = args
+        .public_key
+        .as_deref()
+        .map(parse_public_key)
+        .transpose()?;
+
+    // Ask for the password up front so nobody has to wait for the match
+    let password = match public_key {
+        Some(_) => None,
+        None => Some(read_keystore_password(args.password_file.as_deref(), true)?),
+    };
+
+    Logger::header("Vanity EOA Key Miner");
+    let mode = match nonce {
+        Some(nonce) => format!("EOA deployer (CREATE at nonce {})", nonce),
+        None => "EOA".to_string(),
+    };
+    if let Some(public_key) = &args.public_key {
+        Logger::info("Mode", &format!("{} with split key", mode));
+        Logger::info("Public Key", public_key);
+    } else {
+        Logger::info("Mode", &mode);
+        Logger::info("Keystore Dir", &args.keystore_dir);
+    }
+    Logger::info("Pattern", &pattern_summary(&args.pattern));
+    log_bit_constraints(&args.pattern);
+    Logger::info("Max Keys", &format_number(args.max_iterations));
+    Logger::info("CPU Cores", &rayon::current_num_threads().to_string());
+    Logger::separator();
+
+    let (result, stats) = run_search("Mining keys...", "key", |found, total_checked| {
+        search_keys(
+            &pattern,
+            public_key.as_ref(),
+            nonce,
+            args.max_iterations,
+            found,
+            total_checked,
+        )
+    });
+
+    match (result, password) {
+        (Some(eoa), Some(password)) => {
+            Logger::found_result("Match discovered!");
+            Logger::separator();
+            log_eoa_match(&eoa, nonce, args.pattern.case_sensitive);
+            save_key(
+                &eoa.secret_key,
+                &args.keystore_dir,
+                &password,
+                args.print_private_key,
+            );
+        }
+        (Some(eoa), None) => {
+            let offset = format!("0x{}", alloy::hex::encode(eoa.secret_key.to_bytes()));
+            Logger::found_result("Match discovered!");
+            Logger::separator();
+            log_eoa_match(&eoa, nonce, args.pattern.case_sensitive);
+            Logger::info("Offset", &offset);
+            Logger::info(
+                "Next Step",
+                &format!("ca-miner combine-key {} (on a trusted machine)", offset),
+            );
+        }
+        (None, _) => Logger::no_result(stats.unit),
+    }
+
+    stats.print();
+    Ok(())
+}
+
+fn run_mnemonic_search(args: &MnemonicArgs) -> Result<()> {
+    let pattern = args.pattern.compile()?;
+
+    // Ask for the password up front so nobody has to wait for the match
+    let password = if args.plaintext {
+        None
+    } else {
+        Some(read_keystore_password(args.password_file.as_deref(), true)?)
+    };
+
+    Logger::header("BIP-39 Mnemonic Miner");
+    Logger::info("Mode", "Mnemonic");
+    Logger::info("Pattern", &pattern_summary(&args.pattern));
+    log_bit_constraints(&args.pattern);
+    Logger::info("Derivation Path", &args.derivation_path);
+    Logger::info("Words", &args.word_count.to_string());
+    Logger::info("Max Mnemonics", &format_number(args.max_iterations));
+    Logger::info("Output Dir", &args.output_dir);
+    Logger::info("CPU Cores", &rayon::current_num_threads().to_string());
+    Logger::separator();
+
+    let cursor = SaltCursor::new(0, args.max_iterations);
+    let (result, stats) = run_search("Mining mnemonics...", "mnemonic", |found, total_checked| {
+        search_mnemonics(
+            &pattern,
+            &args.derivation_path,
+            args.word_count,
+            &cursor,
+            found,
+            total_checked,
+        )
+    });
+
+    match result? {
+        Some(found) => {
+            Logger::found_result("Match discovered!");
+            Logger::separator();
+            log_address(&found.address, args.pattern.case_sensitive);
+            Logger::info("Derivation Path", &args.derivation_path);
+
+            // The phrase only exists in memory, so a failed write must not lose it
+            let path = write_with_retry(&args.output_dir, "mnemonic file", |dir| match &password {
+                Some(password) => write_mnemonic_keystore(dir, &found, password.as_bytes()),
+                None => write_mnemonic(dir, &found),
+            });
+            let print = match &path {
+                Some(path) => {
+                    Logger::info("Mnemonic File", &path.display().to_string());
+                    if password.is_none() {
+                        Logger::warning("The mnemonic file is not encrypted");
+                    }
+                    args.print_mnemonic
+                }
+                None => args.print_mnemonic || confirm("Print the mnemonic in plaintext instead?"),
+            };
+            if print {
+                Logger::info("Mnemonic", &found.phrase);
+                Logger::warning("The mnemonic was printed in plaintext; clear your terminal");
+            } else if path.is_none() {
+                Logger::warning("The mnemonic was not saved");
+            }
+        }
+        None => Logger::no_result(stats.unit),
+    }
+
+    stats.print();
+    Ok(())
+}
+
+fn run_decrypt_mnemonic(args: &DecryptMnemonicArgs) -> Result<()> {
+    let password = read_keystore_password(args.password_file.as_deref(), false)?;
+    let phrase = read_mnemonic_keystore(Path::new(&args.file), password.as_bytes())
+        .with_context(|| format!("Failed to decrypt '{}'", args.file))?;
+
+    Logger::header("Mnemonic Decryption");
+    log_address(&derive_address(&phrase, &args.derivation_path)?, true);
+    Logger::info("Derivation Path", &args.derivation_path);
+    Logger::info("Mnemonic", &phrase);
+    Logger::warning("The mnemonic was printed in plaintext; clear your terminal");
+    Ok(())
+}
+
+fn run_keyless_search(args: &KeylessArgs) -> Result<()> {
+    let pattern = args.pattern.compile()?;
+    let init_code = InitCode::load(&args.init_code, &[])?;
+    let deployment = KeylessDeployment {
+        init_code: init_code.bytes.clone().into(),
+        gas_price: args.gas_price,
+        gas_limit: args.gas_limit,
+        variation: args.vary,
+    };
+    deployment.check_counters(args.max_iterations)?;
+
+    Logger::header("Keyless Deployment Miner");
+    Logger::info("Mode", "Keyless (Nick's method)");
+    Logger::info(
+        "Init Code",
+        &format!(
+            "{} bytes from {}",
+            format_number(init_code.bytes.len() as u64),
+            init_code.source
+        ),
+    );
+    Logger::info("Gas Price", &format!("{} wei", args.gas_price));
+    Logger::info("Gas Limit", &format_number(args.gas_limit));
+    Logger::info("Mined Field", &format!("{:?}", args.vary));
+    Logger::info("Target", &format!("{:?}", args.target));
+    Logger::info("Pattern", &pattern_summary(&args.pattern));
+    log_bit_constraints(&args.pattern);
+    Logger::info("Max Transactions", &format_number(args.max_iterations));
+    Logger::info("CPU Cores", &rayon::current_num_threads().to_string());
+    Logger::separator();
+
+    let cursor = SaltCursor::new(0, args.max_iterations);
+    let (result, stats) = run_search(
+        "Mining transactions...",
+        "transaction",
+        |found, total_checked| {
+            search_keyless(
+                &deployment,
+                &pattern,
+                args.target,
+                &cursor,
+                found,
+                total_checked,
+            )
+        },
+    );
+
+    match result {
+        Some(tx) => {
+            Logger::found_result("Match discovered!");
+            Logger::separator();
+            match args.target {
+                KeylessTarget::Contract => {
+                    log_address(&tx.contract, args.pattern.case_sensitive);
+                    Logger::info("Sender", &tx.sender.to_string());
+                }
+                KeylessTarget::Sender => {
+                    log_address(&tx.sender, args.pattern.case_sensitive);
+                    Logger::info("Contract", &tx.contract.to_string());
+                }
+            }
+            Logger::info("Counter", &tx.counter.to_string());
+            Logger::info("Gas Price", &format!("{} wei", tx.gas_price));
+            Logger::info(
+                "Signature",
+                &format!("v=27 r={:#x} s={:#x}", tx.r, KEYLESS_SIGNATURE_VALUE),
+            );
+            Logger::info("Fund Sender With", &format!("{} wei", tx.funding()));
+            Logger::info("Transaction Hash", &tx.hash().to_string());
+            Logger::info("Raw Transaction", &tx.raw_transaction.to_string());
+        }
+        None => Logger::no_result(stats.unit),
+    }
+
+    stats.print();
+    Ok(())
+}
+
+fn run_combine_key(args: &CombineKeyArgs) -> Result<()> {
+    let offset = parse_secret_key(&args.offset)?;
+    let private_key = match &args.private_key_file {
+        Some(path) => Zeroizing::new(
+            std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read private key file '{}'", path))?,
+        ),
+        None => Zeroizing::new(rpassword::prompt_password("Private key: ")?),
+    };
+    let secret_key = combine_keys(&parse_secret_key(&private_key)?, &offset)?;
+    let password = read_keystore_password(args.password_file.as_deref(), true)?;
+
+    Logger::header("Split-Key Combination");
+    log_address(&PrivateKeySigner::from(secret_key.clone()).address(), true);
+    save_key(
+        &secret_key,
+        &args.keystore_dir,
+        &password,
+        args.print_private_key,
+    );
+    Ok(())
+}
+
+fn log_eoa_match(eoa: &EoaMatch, nonce: Option<u64>, case_sensitive: bool) {
+    log_address(&eoa.address, case_sensitive);
+    if let (Some(contract), Some(nonce)) = (eoa.contract, nonce) {
+        let contract = if case_sensitive {
+            contract.to_checksum(None)
+        } else {
+            contract.to_string()
+        };
+        Logger::info(&format!("Contract (nonce {})", nonce), &contract);
+    }
+}
+
+fn log_address(address: &Address, case_sensitive: bool) {
+    if case_sensitive {
+        Logger::info(
+            "Address",
+            &format!("{} (EIP-55 checksum)", address.to_checksum(None)),
+        );
+    } else {
+        Logger::info("Address", &address.to_string());
+    }
+}
+
+/// Write `secret_key` to an encrypted keystore, printing it only when asked to. The key only
+/// exists in memory, so a failed write offers another directory or printing it instead.
+fn save_key(secret_key: &SecretKey, keystore_dir: &str, password: &str, print_private_key: bool) {
+    let keystore = write_with_retry(keystore_dir, "keystore", |dir| {
+        write_keystore(dir, secret_key, password.as_bytes())
+    });
+    let print = match &keystore {
+        Some(path) => {
+            Logger::info("Keystore", &path.display().to_string());
+            print_private_key
+        }
+        None => print_private_key || confirm("Print the private key in plaintext instead?"),
+    };
+    if print {
+        let private_key =
+            Zeroizing::new(format!("0x{}", alloy::hex::encode(secret_key.to_bytes())));
+        Logger::info("Private Key", &private_key);
+        Logger::warning("The private key was printed in plaintext; clear your terminal");
+    } else if keystore.is_none() {
+        Logger::warning("The private key was not saved");
+    }
+}
+
+/// Run `write` on `dir`, asking for another directory after each failure until it succeeds
+/// or the answer is empty
+fn write_with_retry(
+    dir: &str,
+    what: &str,
+    write: impl Fn(&Path) -> Result<PathBuf>,
+) -> Option<PathBuf> {
+    let mut dir = dir.to_string();
+    loop {
+        match write(Path::new(&dir)) {
+            Ok(path) => return Some(path),
+            Err(error) => {
+                Logger::error(&format!(
+                    "Failed to write the {} to '{}': {:#}",
+                    what, dir, error
+                ));
+                let answer = prompt("Directory to retry in (empty to give up): ");
+                if answer.is_empty() {
+                    return None;
+                }
+                dir = answer;
+            }
+        }
+    }
+}
+
+/// Yes/no question on the terminal; anything but "y" or "yes" is a no
+fn confirm(question: &str) -> bool {
+    let answer = prompt(&format!("{} [y/N] ", question));
+    answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes")
+}
+
+/// Line read from stdin after `message`, trimmed; empty when stdin is closed
+fn prompt(message: &str) -> String {
+    use std::io::Write;
+
+    print!("{}", message);
+    let _ = std::io::stdout().flush();
+    let mut answer = String::new();
+    match std::io::stdin().read_line(&mut answer) {
+        Ok(_) => answer.trim().to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+/// Keystore password from `path` (trailing newline stripped) or an interactive prompt, asked
+/// twice when `confirm` is set
+fn read_keystore_password(path: Option<&str>, confirm: bool) -> Result<Zeroizing<String>> {
+    let password = match path {
+        Some(path) => {
+            let mut password = Zeroizing::new(
+                std::fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read password file '{}'", path))?,
+            );
+            let len = password.trim_end_matches(['\r', '\n']).len();
+            password.truncate(len);
+            password
+        }
+        None => {
+            let password = Zeroizing::new(rpassword::prompt_password("Keystore password: ")?);
+            if confirm {
+                let again = Zeroizing::new(rpassword::prompt_password("Confirm password: ")?);
+                if password != again {
+                    anyhow::bail!("Passwords do not match");
+                }
+            }
+            password
+        }
+    };
+    if password.is_empty() {
+        anyhow::bail!("Keystore password must not be empty");
+    }
+    Ok(password)
+}
+
+/// Work counted by a search and how long it took
+struct SearchStats {
+    checked: u64,
+    elapsed: Duration,
+    unit: &'static str,
+}
+
+impl SearchStats {
+    fn print(&self) {
+        let rate = self.checked as f64 / self.elapsed.as_secs_f64();
+        Logger::print_metrics(self.checked, rate, self.elapsed.as_secs_f64(), self.unit);
+    }
+}
+
+/// Run `search` with a progress bar fed by the live status reporter. The search receives the
+/// flag that stops the reporter and the counter it reads; the reporter is stopped once the
+/// search returns. `unit` names one searched item in the rates and messages.
+fn run_search<R>(
+    message: &str,
+    unit: &'static str,
+    search: impl FnOnce(&AtomicBool, &AtomicU64) -> R,
+) -> (R, SearchStats) {
     let start_time = Instant::now();
     let found = Arc::new(AtomicBool::new(false));
     let total_checked = Arc::new(AtomicU64::new(0));
 
-    // Create batches for parallel processing
-    let batches: Vec<u64> = (args.start_salt()..args.start_salt() + args.max_iterations())
-        .step_by(args.batch_size() as usize)
-        .collect();
-
-    Logger::info("Processing Batches", &format_number(batches.len() as u64));
     Logger::mining_start();
-
-    // Create progress bar
-    let progress_bar = Logger::create_progress_bar("Mining salts...");
-
-    // Start live status reporter thread
+    let progress_bar = Logger::create_progress_bar(message);
     let found_clone = Arc::clone(&found);
     let total_checked_clone = Arc::clone(&total_checked);
     let pb_clone = progress_bar.clone();
     let status_handle = thread::spawn(move || {
-        run_status_reporter(found_clone, total_checked_clone, start_time, pb_clone);
+        run_status_reporter(found_clone, total_checked_clone, start_time, pb_clone, unit);
     });
 
-    // Process batches in parallel using Rayon
-    let result = batches
-        .par_iter()
-        .map(|&batch_start| {
-            let batch_size = std::cmp::min(
-                args.batch_size(),
-                args.start_salt() + args.max_iterations() - batch_start,
-            );
-            let result = process_batch(&config, batch_start, batch_size, &found, args.random());
-            total_checked.fetch_add(result.checked, Ordering::Relaxed);
-            result
-        })
-        .find_any(|result| result.found);
+    let result = search(&found, &total_checked);
 
     let elapsed = start_time.elapsed();
-
-    // Signal the status thread to stop
     found.store(true, Ordering::Relaxed);
-
-    // Stop the status reporter
     status_handle.join().unwrap();
     progress_bar.finish_and_clear();
 
-    display_results(result, elapsed, &total_checked, &config);
-
-    Ok(())
+    let stats = SearchStats {
+        checked: total_checked.load(Ordering::Relaxed),
+        elapsed,
+        unit,
+    };
+    (result, stats)
 }
 
 fn run_status_reporter(
@@ -240,6 +1232,7 @@ fn run_status_reporter(
     total_checked: Arc<AtomicU64>,
     start_time: Instant,
     pb: ProgressBar,
+    unit: &str,
 ) {
     let mut last_checked = 0u64;
     let mut last_time = Instant::now();
@@ -258,7 +1251,7 @@ fn run_status_reporter(
             let avg_rate = current_checked as f64 / total_elapsed;
 
             pb.set_message(format!(
-                "Checked: {} | Current: {:.0} s/sec | Avg: {:.0} s/sec",
+                "Checked: {} | Current: {:.0} {unit}s/sec | Avg: {:.0} {unit}s/sec",
                 format_number(current_checked),
                 rate,
                 avg_rate
@@ -272,9 +1265,10 @@ fn run_status_reporter(
 
 fn display_results(
     result: Option<MinerResult>,
-    elapsed: Duration,
-    total_checked: &Arc<AtomicU64>,
+    stats: &SearchStats,
     config: &MinerConfig,
+    raw_salt_label: &str,
+    final_salt_label: &str,
 ) {
     match result {
         Some(result) if result.found => {
@@ -284,8 +1278,31 @@ fn display_results(
 
             Logger::found_result("Match discovered!");
             Logger::separator();
-            Logger::info("Raw Salt", &format!("0x{:016x}", raw_salt));
-            Logger::info("Final Salt", &final_salt.to_string());
+            if let MiningMode::InitCode(variation) = &config.mode {
+                Logger::info("Salt", &variation.salt.to_string());
+                Logger::info(
+                    "Counter",
+                    &format!("0x{}", alloy::hex::encode(variation.counter(&raw_salt))),
+                );
+                Logger::info("Init Code Hash", &final_salt.to_string());
+                Logger::info(
+                    "Init Code",
+                    &format!(
+                        "0x{}",
+                        alloy::hex::encode(variation.init_code_at(&raw_salt))
+                    ),
+                );
+            } else {
+                Logger::info(raw_salt_label, &raw_salt.to_string());
+                Logger::info(final_salt_label, &final_salt.to_string());
+            }
+
+            if let MiningMode::Create3 = config.mode {
+                Logger::info(
+                    "Proxy Address",
+                    &get_create3_proxy_address(config, &final_salt).to_string(),
+                );
+            }
 
             // Display address in appropriate format
             if config.case_sensitive {
@@ -296,17 +1313,8 @@ fn display_results(
             } else {
                 Logger::info("Address", &address.to_string());
             }
-
-            // Calculate performance metrics
-            let checked = total_checked.load(Ordering::Relaxed);
-            let rate = checked as f64 / elapsed.as_secs_f64();
-            Logger::print_metrics(checked, rate, elapsed.as_secs_f64());
-        }
-        _ => {
-            Logger::no_result();
-            let checked = total_checked.load(Ordering::Relaxed);
-            let rate = checked as f64 / elapsed.as_secs_f64();
-            Logger::print_metrics(checked, rate, elapsed.as_secs_f64());
         }
+        _ => Logger::no_result(stats.unit),
     }
+    stats.print();
 }
@@ -1,60 +1,77 @@
 use alloy::primitives::{Address, B256};
-use rand::Rng;
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::address::{check_address_match, get_deployed_address};
+use crate::address::{check_address_match, AddressDeriver};
 use crate::config::MinerConfig;
-use crate::utils::to_bytes32;
 
 #[derive(Debug)]
 pub struct MinerResult {
     pub found: bool,
-    pub raw_salt: Option<u64>,
+    pub raw_salt: Option<B256>,
     pub final_salt: Option<B256>,
     pub address: Option<Address>,
     pub checked: u64,
 }
 
+/// Number of salts derived per [`AddressDeriver::derive_batch`] call
+const CHUNK_SIZE: usize = 64;
+
 pub fn process_batch(
     config: &MinerConfig,
-    start_salt: u64,
+    start_index: u64,
     batch_size: u64,
     found: &AtomicBool,
     use_random: bool,
 ) -> MinerResult {
     let mut rng = if use_random { Some(rand::rng()) } else { None };
+    let deriver = AddressDeriver::new(config);
+
+    let mut salts = [B256::ZERO; CHUNK_SIZE];
+    let mut final_salts = [B256::ZERO; CHUNK_SIZE];
+    let mut addresses = [Address::ZERO; CHUNK_SIZE];
 
-    for i in 0..batch_size {
+    let mut checked = 0;
+    while checked < batch_size {
         if found.load(Ordering::Relaxed) {
             return MinerResult {
                 found: false,
                 raw_salt: None,
                 final_salt: None,
                 address: None,
-                checked: i,
+                checked,
             };
         }
 
-        let current_salt = if use_random {
-            rng.as_mut().unwrap().random::<u64>()
-        } else {
-            start_salt + i
-        };
-
-        let raw_salt_bytes = to_bytes32(current_salt);
-        let final_salt = config.compute_final_salt(&raw_salt_bytes);
-        let deployed_addr = get_deployed_address(config, &final_salt);
-
-        if check_address_match(&deployed_addr, config) {
-            found.store(true, Ordering::Relaxed);
-            return MinerResult {
-                found: true,
-                raw_salt: Some(current_salt),
-                final_salt: Some(final_salt),
-                address: Some(deployed_addr),
-                checked: i + 1,
+        let chunk = std::cmp::min(CHUNK_SIZE as u64, batch_size - checked) as usize;
+        for (j, salt) in salts.iter_mut().enumerate().take(chunk) {
+            *salt = match rng.as_mut() {
+                Some(rng) => config.salt_template.random_salt(rng),
+                None => config
+                    .salt_template
+                    .salt_at(start_index.wrapping_add(checked + j as u64)),
             };
         }
+
+        deriver.derive_batch(
+            &salts[..chunk],
+            &mut final_salts[..chunk],
+            &mut addresses[..chunk],
+        );
+
+        for j in 0..chunk {
+            if check_address_match(&addresses[j], config) {
+                found.store(true, Ordering::Relaxed);
+                return MinerResult {
+                    found: true,
+                    raw_salt: Some(salts[j]),
+                    final_salt: Some(final_salts[j]),
+                    address: Some(addresses[j]),
+                    checked: checked + j as u64 + 1,
+                };
+            }
+        }
+
+        checked += chunk as u64;
     }
 
     MinerResult {
@@ -69,7 +86,10 @@ pub fn process_batch(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{MinerConfig, MiningMode};
+    use crate::address::CREATE3_PROXY_INITCODE_HASH;
+    use crate::config::{MinerConfig, MiningMode, SaltRecipe};
+    use crate::pattern::AddressPattern;
+    use crate::salt::SaltTemplate;
     use alloy::primitives::{address, b256};
 
     fn create_test_config() -> MinerConfig {
@@ -79,14 +99,14 @@ mod tests {
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
             )
             .to_vec(),
-            prefix_bytes: b"00".to_vec(), // Very permissive pattern for testing
-            prefix_len: 2,
-            postfix_bytes: Vec::new(),
-            postfix_len: 0,
+            pattern: AddressPattern::prefix("00", false).unwrap(), // Very permissive pattern for testing
             mode: MiningMode::Create2,
             case_sensitive: false,
             postfix_only: false,
             dual_matching: false,
+            salt_template: SaltTemplate::default(),
+            salt_recipe: SaltRecipe::Raw,
+            proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
         }
     }
 
@@ -97,14 +117,16 @@ mod tests {
                 "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef"
             )
             .to_vec(),
-            prefix_bytes: b"ffffffffffffffffffffffffffffffffffffffff".to_vec(), // Impossible pattern
-            prefix_len: 40, // Full address match - extremely unlikely
-            postfix_bytes: Vec::new(),
-            postfix_len: 0,
+            // Full address match - extremely unlikely
+            pattern: AddressPattern::prefix("ffffffffffffffffffffffffffffffffffffffff", false)
+                .unwrap(),
             mode: MiningMode::Create2,
             case_sensitive: false,
             postfix_only: false,
             dual_matching: false,
+            salt_template: SaltTemplate::default(),
+            salt_recipe: SaltRecipe::Raw,
+            proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
         }
     }
 
@@ -182,7 +204,7 @@ mod tests {
     fn test_miner_result_debug() {
         let result = MinerResult {
             found: true,
-            raw_salt: Some(123),
+            raw_salt: Some(B256::with_last_byte(123)),
             final_salt: Some(B256::ZERO),
             address: Some(Address::ZERO),
             checked: 1,
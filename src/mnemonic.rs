@@ -0,0 +1,223 @@
+use alloy::primitives::Address;
+use alloy::signers::local::coins_bip39::{English, Mnemonic};
+use alloy::signers::utils::secret_key_to_address;
+use anyhow::Result;
+use k256::ecdsa::SigningKey;
+use k256::elliptic_curve::rand_core::OsRng;
+use k256::elliptic_curve::zeroize::Zeroizing;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Instant;
+
+use crate::pattern::AddressPattern;
+use crate::scheduler::{ChunkSizer, SaltCursor};
+
+/// First account of the standard Ethereum BIP-44 path
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/60'/0'/0/0";
+
+/// BIP-39 mnemonic whose derived account matches the pattern
+pub struct MnemonicMatch {
+    pub phrase: Zeroizing<String>,
+    pub address: Address,
+}
+
+/// Address of the account derived from `mnemonic` at `path`
+fn mnemonic_address(mnemonic: &Mnemonic<English>, path: &str) -> Result<Address> {
+    let xpriv = mnemonic.derive_key(path, None)?;
+    let key: &SigningKey = xpriv.as_ref();
+    Ok(secret_key_to_address(key))
+}
+
+/// Address of the account derived from a mnemonic phrase at `path`
+pub fn derive_address(phrase: &str, path: &str) -> Result<Address> {
+    mnemonic_address(&Mnemonic::<English>::new_from_phrase(phrase)?, path)
+}
+
+/// Generate random `word_count`-word mnemonics on every thread of the current rayon pool
+/// until the account at `path` matches `pattern` or the cursor is exhausted.
+///
+/// Workers claim chunks from `cursor` like the salt miners; `checked` counts mnemonics.
+pub fn search_mnemonics(
+    pattern: &AddressPattern,
+    path: &str,
+    word_count: usize,
+    cursor: &SaltCursor,
+    found: &AtomicBool,
+    checked: &AtomicU64,
+) -> Result<Option<MnemonicMatch>> {
+    // Reject bad word counts and paths before spawning workers
+    mnemonic_address(
+        &Mnemonic::<English>::new_with_count(&mut OsRng, word_count)?,
+        path,
+    )?;
+
+    let result = Mutex::new(None);
+
+    rayon::broadcast(|ctx| {
+        let mut sizer = ChunkSizer::new(0, ctx.num_threads());
+
+        while !found.load(Ordering::Relaxed) {
+            let Some((_, count)) = cursor.claim(sizer.next_size(cursor.remaining())) else {
+                break;
+            };
+
+            let chunk_start = Instant::now();
+            for _ in 0..count {
+                if found.load(Ordering::Relaxed) {
+                    break;
+                }
+                let Ok(mnemonic) = Mnemonic::<English>::new_with_count(&mut OsRng, word_count)
+                else {
+                    continue;
+                };
+                let Ok(address) = mnemonic_address(&mnemonic, path) else {
+                    continue;
+                };
+                checked.fetch_add(1, Ordering::Relaxed);
+
+                if pattern.matches(&address) {
+                    found.store(true, Ordering::Relaxed);
+                    result.lock().unwrap().get_or_insert(MnemonicMatch {
+                        phrase: Zeroizing::new(mnemonic.to_phrase()),
+                        address,
+                    });
+                    break;
+                }
+            }
+            sizer.record(count, chunk_start.elapsed());
+        }
+    });
+
+    Ok(result.into_inner().unwrap())
+}
+
+/// Encrypt the phrase into a Web3 Secret Storage v3 file `<dir>/<address>.mnemonic.json`.
+/// Existing files are never overwritten.
+pub fn write_mnemonic_keystore(
+    dir: &Path,
+    found: &MnemonicMatch,
+    password: &[u8],
+) -> Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let name = format!("{}.mnemonic.json", alloy::hex::encode(found.address));
+    let path = dir.join(&name);
+    if path.exists() {
+        anyhow::bail!("{} already exists", path.display());
+    }
+
+    eth_keystore::encrypt_key(
+        dir,
+        &mut OsRng,
+        found.phrase.as_bytes(),
+        password,
+        Some(&name),
+    )?;
+    if read_mnemonic_keystore(&path, password)? != found.phrase {
+        anyhow::bail!("Mnemonic keystore does not round-trip");
+    }
+    Ok(path)
+}
+
+/// Decrypt a phrase written by [`write_mnemonic_keystore`]
+pub fn read_mnemonic_keystore(path: &Path, password: &[u8]) -> Result<Zeroizing<String>> {
+    let bytes = Zeroizing::new(eth_keystore::decrypt_key(path, password)?);
+    Ok(Zeroizing::new(std::str::from_utf8(&bytes)?.to_string()))
+}
+
+/// Write the phrase in plaintext to `<dir>/<address>.mnemonic`, readable only by the owner
+/// on Unix. Existing files are never overwritten.
+pub fn write_mnemonic(dir: &Path, found: &MnemonicMatch) -> Result<PathBuf> {
+    use std::io::Write;
+
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{}.mnemonic", alloy::hex::encode(found.address)));
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(&path)?;
+    writeln!(file, "{}", found.phrase.as_str())?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::address;
+
+    const TEST_PHRASE: &str = "test test test test test test test test test test test junk";
+
+    #[test]
+    fn test_derive_address() {
+        assert_eq!(
+            derive_address(TEST_PHRASE, DEFAULT_DERIVATION_PATH).unwrap(),
+            address!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266")
+        );
+        assert_eq!(
+            derive_address(TEST_PHRASE, "m/44'/60'/0'/0/1").unwrap(),
+            address!("70997970c51812dc3a010c7d01b50e0d17dc79c8")
+        );
+        assert!(derive_address(TEST_PHRASE, "m/44'/60'/x").is_err());
+    }
+
+    #[test]
+    fn test_search_mnemonics() {
+        let pattern = AddressPattern::prefix("a", false).unwrap();
+        let cursor = SaltCursor::new(0, 10_000);
+        let found = AtomicBool::new(false);
+        let checked = AtomicU64::new(0);
+
+        let result = search_mnemonics(
+            &pattern,
+            DEFAULT_DERIVATION_PATH,
+            12,
+            &cursor,
+            &found,
+            &checked,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(result.phrase.split(' ').count(), 12);
+        assert_eq!(
+            derive_address(&result.phrase, DEFAULT_DERIVATION_PATH).unwrap(),
+            result.address
+        );
+        assert!(pattern.matches(&result.address));
+
+        let cursor = SaltCursor::new(0, 1);
+        let found = AtomicBool::new(false);
+        assert!(search_mnemonics(
+            &pattern,
+            DEFAULT_DERIVATION_PATH,
+            13,
+            &cursor,
+            &found,
+            &checked
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_mnemonic_keystore_round_trip() {
+        let dir = std::env::temp_dir().join(format!("ca-miner-mnemonic-{}", std::process::id()));
+        let found = MnemonicMatch {
+            phrase: Zeroizing::new(TEST_PHRASE.to_string()),
+            address: address!("f39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+        };
+
+        let path = write_mnemonic_keystore(&dir, &found, b"password").unwrap();
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("junk"));
+        assert_eq!(
+            read_mnemonic_keystore(&path, b"password").unwrap().as_str(),
+            TEST_PHRASE
+        );
+        assert!(read_mnemonic_keystore(&path, b"wrong").is_err());
+        assert!(write_mnemonic_keystore(&dir, &found, b"password").is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,160 @@
+use alloy::primitives::Address;
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use crate::address::get_create_address;
+use crate::pattern::AddressPattern;
+
+/// Nonces hashed per parallel task
+const NONCE_CHUNK_SIZE: u64 = 4096;
+
+/// Upcoming nonce of a deployer whose CREATE address matches the pattern
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonceMatch {
+    pub nonce: u64,
+    pub address: Address,
+}
+
+/// Scan nonces `start..start + count` of `deployer` and return the first `max_matches`
+/// matching nonces in ascending order. Stops early once enough matches are found or
+/// `stop` is set; `checked` is updated after each chunk.
+pub fn scan_nonces(
+    deployer: &Address,
+    pattern: &AddressPattern,
+    start: u64,
+    count: u64,
+    max_matches: usize,
+    checked: &AtomicU64,
+    stop: &AtomicBool,
+) -> Vec<NonceMatch> {
+    scan_nonces_with(
+        |nonce| get_create_address(deployer, nonce),
+        pattern,
+        start,
+        count,
+        max_matches,
+        checked,
+        stop,
+    )
+}
+
+/// [`scan_nonces`] with a custom nonce to address derivation, such as zkSync's
+pub fn scan_nonces_with(
+    address_at: impl Fn(u64) -> Address + Sync,
+    pattern: &AddressPattern,
+    start: u64,
+    count: u64,
+    max_matches: usize,
+    checked: &AtomicU64,
+    stop: &AtomicBool,
+) -> Vec<NonceMatch> {
+    let end = start.saturating_add(count);
+    let mut matches = Vec::new();
+    let mut round_start = start;
+    // A few chunks per thread per round, so scanning stops soon after the last match
+    let round_size = NONCE_CHUNK_SIZE * 4 * rayon::current_num_threads() as u64;
+
+    while round_start < end && matches.len() < max_matches && !stop.load(Ordering::Relaxed) {
+        let round_end = round_start.saturating_add(round_size).min(end);
+        let chunks = (round_end - round_start).div_ceil(NONCE_CHUNK_SIZE);
+
+        let round: Vec<NonceMatch> = (0..chunks)
+            .into_par_iter()
+            .flat_map_iter(|chunk| {
+                let chunk_start = round_start + chunk * NONCE_CHUNK_SIZE;
+                let chunk_end = chunk_start.saturating_add(NONCE_CHUNK_SIZE).min(round_end);
+                checked.fetch_add(chunk_end - chunk_start, Ordering::Relaxed);
+
+                (chunk_start..chunk_end).filter_map(|nonce| {
+                    let address = address_at(nonce);
+                    pattern
+                        .matches(&address)
+                        .then_some(NonceMatch { nonce, address })
+                })
+            })
+            .collect();
+
+        matches.extend(round);
+        round_start = round_end;
+    }
+
+    matches.truncate(max_matches);
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::get_zksync_create_address;
+    use alloy::primitives::address;
+
+    #[test]
+    fn test_scan_nonces_finds_matches_in_order() {
+        let deployer = address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8");
+        let pattern = AddressPattern::prefix("a", false).unwrap();
+        let checked = AtomicU64::new(0);
+        let stop = AtomicBool::new(false);
+
+        let matches = scan_nonces(&deployer, &pattern, 100, 10_000, 5, &checked, &stop);
+
+        assert_eq!(matches.len(), 5);
+        assert!(matches.windows(2).all(|w| w[0].nonce < w[1].nonce));
+        for found in &matches {
+            assert_eq!(found.address, deployer.create(found.nonce));
+            assert!(pattern.matches(&found.address));
+        }
+
+        // Every skipped nonce in between must not match
+        let first = matches[0].nonce;
+        assert!((100..first).all(|nonce| !pattern.matches(&deployer.create(nonce))));
+        assert!(checked.load(Ordering::Relaxed) >= first - 100);
+    }
+
+    #[test]
+    fn test_scan_nonces_with_zksync_derivation() {
+        let deployer = address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8");
+        let pattern = AddressPattern::prefix("b", false).unwrap();
+        let checked = AtomicU64::new(0);
+        let stop = AtomicBool::new(false);
+
+        let matches = scan_nonces_with(
+            |nonce| get_zksync_create_address(&deployer, nonce),
+            &pattern,
+            0,
+            10_000,
+            3,
+            &checked,
+            &stop,
+        );
+
+        assert_eq!(matches.len(), 3);
+        for found in &matches {
+            assert_eq!(
+                found.address,
+                get_zksync_create_address(&deployer, found.nonce)
+            );
+            assert!(pattern.matches(&found.address));
+        }
+    }
+
+    #[test]
+    fn test_scan_nonces_near_u64_max() {
+        let deployer = address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8");
+        let checked = AtomicU64::new(0);
+        let stop = AtomicBool::new(false);
+
+        let matches = scan_nonces(
+            &deployer,
+            &AddressPattern::any(),
+            u64::MAX - 2,
+            10,
+            usize::MAX,
+            &checked,
+            &stop,
+        );
+
+        // Saturates at u64::MAX instead of wrapping
+        assert_eq!(matches.len(), 2);
+        assert_eq!(checked.load(Ordering::Relaxed), 2);
+    }
+}
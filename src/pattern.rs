@@ -0,0 +1,432 @@
+use alloy::primitives::{keccak256, Address, B256};
+use anyhow::Result;
+
+/// Number of hex characters (nibbles) in an address
+pub const ADDRESS_NIBBLES: usize = 40;
+
+/// Wildcard character matching any nibble
+pub const WILDCARD: char = '?';
+
+/// Bit widths of the words an address is split into: bytes 0..8, 8..16 and 16..20
+const WORD_BITS: [usize; 3] = [64, 64, 32];
+
+/// Address pattern compiled into 160-bit mask/value words.
+///
+/// Matching is three masked integer compares. Case-sensitive patterns additionally
+/// record which nibbles must be upper- or lowercase in the EIP-55 checksum; that check
+/// only runs once the nibble values already match.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AddressPattern {
+    mask: [u64; 3],
+    value: [u64; 3],
+    /// Bit `i` set when the EIP-55 case of nibble `i` is constrained
+    case_mask: u64,
+    /// Bit `i` set when nibble `i` must be uppercase
+    case_value: u64,
+}
+
+impl AddressPattern {
+    /// Pattern matching every address
+    pub const fn any() -> Self {
+        Self {
+            mask: [0; 3],
+            value: [0; 3],
+            case_mask: 0,
+            case_value: 0,
+        }
+    }
+
+    /// Compile a pattern anchored at the start of the address
+    pub fn prefix(pattern: &str, case_sensitive: bool) -> Result<Self> {
+        let nibbles = parse_pattern(pattern)?;
+        Ok(Self::from_nibbles(&nibbles, 0, case_sensitive))
+    }
+
+    /// Compile a pattern anchored at the end of the address
+    pub fn postfix(pattern: &str, case_sensitive: bool) -> Result<Self> {
+        let nibbles = parse_pattern(pattern)?;
+        Ok(Self::from_nibbles(
+            &nibbles,
+            ADDRESS_NIBBLES - nibbles.len(),
+            case_sensitive,
+        ))
+    }
+
+    /// Pattern requiring `address & mask == value`
+    pub fn bits(mask: &Address, value: &Address) -> Result<Self> {
+        let (mask, value) = (address_words(mask), address_words(value));
+        if (0..3).any(|w| value[w] & !mask[w] != 0) {
+            anyhow::bail!("Value sets bits outside the mask and can never match");
+        }
+        Ok(Self {
+            mask,
+            value,
+            case_mask: 0,
+            case_value: 0,
+        })
+    }
+
+    /// Compile `--mask`/`--value` pairs, each requiring `address & mask == value`
+    pub fn from_bit_constraints(masks: &[String], values: &[String]) -> Result<Self> {
+        if masks.len() != values.len() {
+            anyhow::bail!(
+                "Got {} --mask and {} --value options; they must come in pairs",
+                masks.len(),
+                values.len()
+            );
+        }
+        masks
+            .iter()
+            .zip(values)
+            .try_fold(Self::any(), |pattern, (mask, value)| {
+                pattern.and(&Self::bits(&parse_bits(mask)?, &parse_bits(value)?)?)
+            })
+    }
+
+    /// Compile the CLI pattern options. With `postfix_only` the main pattern is anchored at
+    /// the end instead; `postfix` adds a second, end-anchored pattern for dual matching.
+    pub fn from_args(
+        prefix: &str,
+        postfix: Option<&str>,
+        postfix_only: bool,
+        case_sensitive: bool,
+    ) -> Result<Self> {
+        let main = if postfix_only {
+            Self::postfix(prefix, case_sensitive)?
+        } else {
+            Self::prefix(prefix, case_sensitive)?
+        };
+        match postfix {
+            Some(postfix) => main.and(&Self::postfix(postfix, case_sensitive)?),
+            None => Ok(main),
+        }
+    }
+
+    fn from_nibbles(nibbles: &[char], offset: usize, case_sensitive: bool) -> Self {
+        let mut pattern = Self::any();
+        for (i, &c) in nibbles.iter().enumerate() {
+            let pos = offset + i;
+            let Some(nibble) = c.to_digit(16) else {
+                // Wildcard
+                continue;
+            };
+
+            let (word, shift) = nibble_slot(pos);
+            pattern.mask[word] |= 0xf << shift;
+            pattern.value[word] |= (nibble as u64) << shift;
+
+            if case_sensitive && c.is_ascii_alphabetic() {
+                pattern.case_mask |= 1 << pos;
+                if c.is_ascii_uppercase() {
+                    pattern.case_value |= 1 << pos;
+                }
+            }
+        }
+        pattern
+    }
+
+    /// Combine with another pattern; the result matches only when both do
+    pub fn and(&self, other: &Self) -> Result<Self> {
+        let mut combined = self.clone();
+        for w in 0..3 {
+            let overlap = self.mask[w] & other.mask[w];
+            if (self.value[w] ^ other.value[w]) & overlap != 0 {
+                anyhow::bail!("Patterns overlap with conflicting nibbles and can never match");
+            }
+            combined.mask[w] |= other.mask[w];
+            combined.value[w] |= other.value[w];
+        }
+
+        let case_overlap = self.case_mask & other.case_mask;
+        if (self.case_value ^ other.case_value) & case_overlap != 0 {
+            anyhow::bail!("Patterns overlap with conflicting letter case and can never match");
+        }
+        combined.case_mask |= other.case_mask;
+        combined.case_value |= other.case_value;
+        Ok(combined)
+    }
+
+    /// Whether any nibble requires a specific EIP-55 case
+    pub fn is_case_sensitive(&self) -> bool {
+        self.case_mask != 0
+    }
+
+    /// Number of constrained bits, including checksum case bits
+    pub fn constrained_bits(&self) -> u32 {
+        self.mask.iter().map(|m| m.count_ones()).sum::<u32>() + self.case_mask.count_ones()
+    }
+
+    #[inline]
+    pub fn matches(&self, addr: &Address) -> bool {
+        let words = address_words(addr);
+        (words[0] & self.mask[0]) == self.value[0]
+            && (words[1] & self.mask[1]) == self.value[1]
+            && (words[2] & self.mask[2]) == self.value[2]
+            && (self.case_mask == 0 || self.matches_checksum_case(addr))
+    }
+
+    fn matches_checksum_case(&self, addr: &Address) -> bool {
+        let hash = checksum_hash(addr);
+
+        // EIP-55: a letter is uppercase iff the corresponding hash nibble is >= 8
+        let mut uppercase = 0u64;
+        for (i, byte) in hash[..ADDRESS_NIBBLES / 2].iter().enumerate() {
+            uppercase |= (((byte >> 7) & 1) as u64) << (2 * i);
+            uppercase |= (((byte >> 3) & 1) as u64) << (2 * i + 1);
+        }
+
+        uppercase & self.case_mask == self.case_value
+    }
+}
+
+/// Strip `0x` and validate the pattern, returning its characters
+fn parse_pattern(pattern: &str) -> Result<Vec<char>> {
+    let clean = pattern.strip_prefix("0x").unwrap_or(pattern);
+    let nibbles: Vec<char> = clean.chars().collect();
+
+    if nibbles.len() > ADDRESS_NIBBLES {
+        anyhow::bail!(
+            "Pattern '{}' is {} nibbles long; addresses only have {}",
+            pattern,
+            nibbles.len(),
+            ADDRESS_NIBBLES
+        );
+    }
+    if let Some(bad) = nibbles
+        .iter()
+        .find(|c| !c.is_ascii_hexdigit() && **c != WILDCARD)
+    {
+        anyhow::bail!(
+            "Pattern '{}' contains invalid character '{}' (expected hex or '{}')",
+            pattern,
+            bad,
+            WILDCARD
+        );
+    }
+    Ok(nibbles)
+}
+
+/// Parse a 160-bit mask or value; shorter hex is zero-padded on the left like an integer
+fn parse_bits(hex: &str) -> Result<Address> {
+    let clean = hex.strip_prefix("0x").unwrap_or(hex);
+    if clean.is_empty()
+        || clean.len() > ADDRESS_NIBBLES
+        || !clean.chars().all(|c| c.is_ascii_hexdigit())
+    {
+        anyhow::bail!(
+            "Invalid mask/value '{}' (expected up to {} hex nibbles)",
+            hex,
+            ADDRESS_NIBBLES
+        );
+    }
+    let padded = format!("{:0>width$}", clean, width = ADDRESS_NIBBLES);
+    Ok(Address::from_slice(&alloy::hex::decode(padded)?))
+}
+
+/// Word index and bit shift of hex position `pos` within [`address_words`]
+fn nibble_slot(pos: usize) -> (usize, usize) {
+    let word = pos / 16;
+    (word, WORD_BITS[word] - 4 * (pos - word * 16 + 1))
+}
+
+#[inline]
+fn address_words(addr: &Address) -> [u64; 3] {
+    let bytes = addr.as_slice();
+    [
+        u64::from_be_bytes(bytes[0..8].try_into().unwrap()),
+        u64::from_be_bytes(bytes[8..16].try_into().unwrap()),
+        u32::from_be_bytes(bytes[16..20].try_into().unwrap()) as u64,
+    ]
+}
+
+/// Compute the EIP-55 checksum hash: keccak256 of the lowercase hex address (without 0x)
+fn checksum_hash(addr: &Address) -> B256 {
+    const HEX_CHARS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = [0u8; ADDRESS_NIBBLES];
+    for (i, byte) in addr.as_slice().iter().enumerate() {
+        hex[i * 2] = HEX_CHARS[(byte >> 4) as usize];
+        hex[i * 2 + 1] = HEX_CHARS[(byte & 0x0f) as usize];
+    }
+    keccak256(hex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::address;
+
+    #[test]
+    fn test_prefix_pattern() {
+        let addr = address!("deadbeefcafebabe1234567890abcdef12345678");
+
+        assert!(AddressPattern::prefix("dead", false)
+            .unwrap()
+            .matches(&addr));
+        assert!(AddressPattern::prefix("0xDEAD", false)
+            .unwrap()
+            .matches(&addr));
+        assert!(!AddressPattern::prefix("cafe", false)
+            .unwrap()
+            .matches(&addr));
+
+        // Partial and odd-length matches
+        assert!(AddressPattern::prefix("de", false).unwrap().matches(&addr));
+        assert!(AddressPattern::prefix("dea", false).unwrap().matches(&addr));
+        assert!(!AddressPattern::prefix("deb", false).unwrap().matches(&addr));
+
+        // Spanning every word boundary
+        let full = "deadbeefcafebabe1234567890abcdef12345678";
+        assert!(AddressPattern::prefix(full, false).unwrap().matches(&addr));
+    }
+
+    #[test]
+    fn test_postfix_pattern() {
+        let addr = address!("1234567890abcdef1234567890abcdefdeadbeef");
+
+        assert!(AddressPattern::postfix("beef", false)
+            .unwrap()
+            .matches(&addr));
+        assert!(!AddressPattern::postfix("cafe", false)
+            .unwrap()
+            .matches(&addr));
+        assert!(AddressPattern::postfix("ef", false).unwrap().matches(&addr));
+        assert!(AddressPattern::postfix("eef", false)
+            .unwrap()
+            .matches(&addr));
+        assert!(AddressPattern::postfix("cdefdeadbeef", false)
+            .unwrap()
+            .matches(&addr));
+    }
+
+    #[test]
+    fn test_wildcards() {
+        let addr = address!("deadbeefcafebabe1234567890abcdef12345678");
+
+        assert!(AddressPattern::prefix("d??d", false)
+            .unwrap()
+            .matches(&addr));
+        assert!(AddressPattern::prefix("????beef", false)
+            .unwrap()
+            .matches(&addr));
+        assert!(!AddressPattern::prefix("????cafe", false)
+            .unwrap()
+            .matches(&addr));
+        assert!(AddressPattern::postfix("5?7?", false)
+            .unwrap()
+            .matches(&addr));
+        assert_eq!(
+            AddressPattern::prefix("????", false).unwrap(),
+            AddressPattern::any()
+        );
+    }
+
+    #[test]
+    fn test_invalid_patterns_fail_fast() {
+        assert!(AddressPattern::prefix("deag", false).is_err());
+        assert!(AddressPattern::prefix("de ad", false).is_err());
+        assert!(AddressPattern::postfix(&"a".repeat(41), false).is_err());
+        assert!(AddressPattern::prefix(&"a".repeat(40), false).is_ok());
+        assert!(AddressPattern::prefix("", false).is_ok());
+    }
+
+    #[test]
+    fn test_checksum_case() {
+        // EIP-55 reference vector: 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed
+        let addr = address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed");
+
+        assert!(AddressPattern::prefix("5aAeb6053F", true)
+            .unwrap()
+            .matches(&addr));
+        assert!(!AddressPattern::prefix("5aaeb6053f", true)
+            .unwrap()
+            .matches(&addr));
+        assert!(AddressPattern::postfix("1BeAed", true)
+            .unwrap()
+            .matches(&addr));
+        assert!(!AddressPattern::postfix("1BEAed", true)
+            .unwrap()
+            .matches(&addr));
+
+        // Case-insensitive compilation ignores letter case entirely
+        assert!(AddressPattern::prefix("5AAEB", false)
+            .unwrap()
+            .matches(&addr));
+        assert!(!AddressPattern::prefix("5AAEB", false)
+            .unwrap()
+            .is_case_sensitive());
+    }
+
+    #[test]
+    fn test_bits_pattern() {
+        let mask = address!("f000000000000000000000000000000000000003");
+        let pattern =
+            AddressPattern::bits(&mask, &address!("a000000000000000000000000000000000000001"))
+                .unwrap();
+
+        assert!(pattern.matches(&address!("a1234567890abcdef1234567890abcdef1234561")));
+        assert!(pattern.matches(&address!("affffffffffffffffffffffffffffffffffffffd")));
+        assert!(!pattern.matches(&address!("a1234567890abcdef1234567890abcdef1234563")));
+        assert!(!pattern.matches(&address!("b1234567890abcdef1234567890abcdef1234561")));
+        assert_eq!(pattern.constrained_bits(), 6);
+
+        assert!(
+            AddressPattern::bits(&mask, &address!("0000000000000000000000000000000000000004"))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_bit_constraints() {
+        let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        // Low byte 0x40, and the top nibble even
+        let pattern = AddressPattern::from_bit_constraints(
+            &strings(&["0xff", "0x1000000000000000000000000000000000000000"]),
+            &strings(&["0x40", "0x0"]),
+        )
+        .unwrap();
+        assert!(pattern.matches(&address!("e000000000000000000000000000000000001140")));
+        assert!(!pattern.matches(&address!("f000000000000000000000000000000000001140")));
+        assert!(!pattern.matches(&address!("e000000000000000000000000000000000001141")));
+        assert_eq!(pattern.constrained_bits(), 9);
+
+        assert_eq!(
+            AddressPattern::from_bit_constraints(&[], &[]).unwrap(),
+            AddressPattern::any()
+        );
+        assert!(AddressPattern::from_bit_constraints(&strings(&["0xff"]), &[]).is_err());
+        assert!(
+            AddressPattern::from_bit_constraints(&strings(&["0xfg"]), &strings(&["0x1"])).is_err()
+        );
+        // Two constraints demanding different values for the same bit
+        assert!(AddressPattern::from_bit_constraints(
+            &strings(&["0x1", "0x3"]),
+            &strings(&["0x1", "0x2"])
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_and_combines_and_detects_conflicts() {
+        let prefix = AddressPattern::prefix("dead", false).unwrap();
+        let postfix = AddressPattern::postfix("beef", false).unwrap();
+        let both = prefix.and(&postfix).unwrap();
+
+        assert!(both.matches(&address!("deadbeefcafebabe1234567890abcdefdeadbeef")));
+        assert!(!both.matches(&address!("deadbeefcafebabe1234567890abcdef12345678")));
+        assert_eq!(both.constrained_bits(), 32);
+
+        // Overlapping but compatible
+        let full = AddressPattern::prefix(&"d".repeat(30), false).unwrap();
+        assert!(full
+            .and(&AddressPattern::postfix(&"d".repeat(20), false).unwrap())
+            .is_ok());
+        assert!(full
+            .and(&AddressPattern::postfix(&"e".repeat(20), false).unwrap())
+            .is_err());
+        assert!(AddressPattern::prefix("A", true)
+            .unwrap()
+            .and(&AddressPattern::prefix("a", true).unwrap())
+            .is_err());
+    }
+}
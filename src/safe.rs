@@ -0,0 +1,96 @@
+use alloy::primitives::{Address, Bytes, U256};
+use alloy::sol;
+use alloy::sol_types::SolCall;
+
+sol! {
+    function setup(
+        address[] _owners,
+        uint256 _threshold,
+        address to,
+        bytes data,
+        address fallbackHandler,
+        address paymentToken,
+        uint256 payment,
+        address paymentReceiver
+    );
+}
+
+/// Parameters of `Safe.setup`, called by the proxy factory right after deployment
+#[derive(Clone, Debug)]
+pub struct SafeSetup {
+    pub owners: Vec<Address>,
+    pub threshold: u64,
+    /// Optional delegate call target for module setup
+    pub to: Address,
+    pub data: Bytes,
+    pub fallback_handler: Address,
+}
+
+impl SafeSetup {
+    /// ABI-encoded `setup` call passed as `initializer` to `createProxyWithNonce`.
+    /// No payment is requested for the deployment.
+    pub fn initializer(&self) -> Vec<u8> {
+        setupCall {
+            _owners: self.owners.clone(),
+            _threshold: U256::from(self.threshold),
+            to: self.to,
+            data: self.data.clone(),
+            fallbackHandler: self.fallback_handler,
+            paymentToken: Address::ZERO,
+            payment: U256::ZERO,
+            paymentReceiver: Address::ZERO,
+        }
+        .abi_encode()
+    }
+}
+
+/// Init code deployed by the factory: `abi.encodePacked(proxyCreationCode, uint256(singleton))`
+pub fn proxy_init_code(proxy_creation_code: &[u8], singleton: &Address) -> Vec<u8> {
+    let mut init_code = proxy_creation_code.to_vec();
+    init_code.extend_from_slice(singleton.into_word().as_slice());
+    init_code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SaltRecipe;
+    use alloy::primitives::{address, keccak256, B256};
+
+    #[test]
+    fn test_safe_initializer() {
+        let setup = SafeSetup {
+            owners: vec![
+                address!("1111111111111111111111111111111111111111"),
+                address!("2222222222222222222222222222222222222222"),
+            ],
+            threshold: 2,
+            to: Address::ZERO,
+            data: Bytes::new(),
+            fallback_handler: address!("fd0732dc9e303f09fcef3a7388ad10a83459ec99"),
+        };
+        let initializer = setup.initializer();
+
+        assert_eq!(&initializer[..4], &[0xb6, 0x3e, 0x80, 0x0d]);
+        // 8 head words, then the owners array (length + 2) and empty data (length)
+        assert_eq!(initializer.len(), 4 + 32 * (8 + 3 + 1));
+        assert_eq!(U256::from_be_slice(&initializer[36..68]), U256::from(2));
+
+        // salt = keccak256(keccak256(initializer) ++ saltNonce)
+        let salt_nonce = B256::with_last_byte(7);
+        let mut preimage = keccak256(&initializer).to_vec();
+        preimage.extend_from_slice(salt_nonce.as_slice());
+        assert_eq!(
+            SaltRecipe::initializer(&initializer).apply(&salt_nonce),
+            keccak256(preimage)
+        );
+    }
+
+    #[test]
+    fn test_proxy_init_code() {
+        let singleton = address!("41675c099f32341bf84bfc5382af534df5c7461a");
+        let init_code = proxy_init_code(&[0x60, 0x80], &singleton);
+        assert_eq!(init_code.len(), 2 + 32);
+        assert_eq!(&init_code[14..], singleton.as_slice());
+    }
+}
@@ -0,0 +1,285 @@
+use alloy::primitives::{B256, U256};
+use anyhow::Result;
+use rand::Rng;
+use std::fmt;
+use std::ops::Range;
+
+/// Number of hex characters (nibbles) in a salt
+const SALT_NIBBLES: usize = 64;
+
+/// Character marking a mined nibble in a salt template
+pub const MINED: char = '?';
+
+/// Layout of the 32-byte salt: fixed nibbles around one contiguous mined region.
+///
+/// Templates are written as 64 characters, hex for fixed nibbles and `?` for mined ones,
+/// e.g. `0x<40 hex caller>` followed by 24 `?` to mine the low 12 bytes. The mined
+/// region doubles as the counter: salt `i` holds `(start + i) mod 2^width` there.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SaltTemplate {
+    /// Fixed bits, zero inside the mined region
+    base: U256,
+    /// Bits of the mined region
+    mask: U256,
+    /// Position of the mined region's least significant bit
+    shift: usize,
+    /// Width of the mined region (counter width) in bits
+    width: usize,
+    /// Counter value of the first salt
+    start: U256,
+}
+
+impl Default for SaltTemplate {
+    /// Low 8 bytes mined, everything else zero: `to_bytes32(start + i)`
+    fn default() -> Self {
+        Self::parse(&format!("0x{}{}", "0".repeat(48), "?".repeat(16))).unwrap()
+    }
+}
+
+impl SaltTemplate {
+    pub fn parse(template: &str) -> Result<Self> {
+        let clean = template.strip_prefix("0x").unwrap_or(template);
+        if clean.chars().count() != SALT_NIBBLES {
+            anyhow::bail!(
+                "Salt template '{}' must have exactly {} nibbles (hex or '{}')",
+                template,
+                SALT_NIBBLES,
+                MINED
+            );
+        }
+        if let Some(bad) = clean
+            .chars()
+            .find(|c| !c.is_ascii_hexdigit() && *c != MINED)
+        {
+            anyhow::bail!(
+                "Salt template '{}' contains invalid character '{}'",
+                template,
+                bad
+            );
+        }
+
+        let first = clean.find(MINED);
+        let last = clean.rfind(MINED);
+        let (Some(first), Some(last)) = (first, last) else {
+            anyhow::bail!(
+                "Salt template '{}' has no mined nibbles ('{}')",
+                template,
+                MINED
+            );
+        };
+        if clean[first..=last].chars().any(|c| c != MINED) {
+            anyhow::bail!(
+                "Mined nibbles in salt template '{}' must be contiguous",
+                template
+            );
+        }
+
+        let base = U256::from_str_radix(&clean.replace(MINED, "0"), 16)?;
+        let width = 4 * (last - first + 1);
+        let shift = 4 * (SALT_NIBBLES - 1 - last);
+        let mask = low_bits(width) << shift;
+
+        Ok(Self {
+            base,
+            mask,
+            shift,
+            width,
+            start: U256::ZERO,
+        })
+    }
+
+    /// Start mining from `start_salt`, given either as a counter value (decimal or hex) or
+    /// as a full 32-byte salt whose fixed nibbles agree with the template.
+    pub fn with_start(mut self, start_salt: &str) -> Result<Self> {
+        let hex = start_salt.strip_prefix("0x");
+        let value = match hex {
+            Some(hex) => U256::from_str_radix(hex, 16),
+            None => start_salt.parse::<U256>(),
+        }
+        .map_err(|e| anyhow::anyhow!("Invalid start salt '{}': {}", start_salt, e))?;
+
+        self.start = if hex.is_some_and(|h| h.len() == SALT_NIBBLES) {
+            // Full salt: take its mined bits as the counter
+            if value & !self.mask != self.base {
+                anyhow::bail!(
+                    "Start salt {} does not match the fixed nibbles of salt template {}",
+                    start_salt,
+                    self
+                );
+            }
+            (value & self.mask) >> self.shift
+        } else {
+            if value > low_bits(self.width) {
+                anyhow::bail!(
+                    "Start salt {} does not fit in the {}-bit mined region",
+                    start_salt,
+                    self.width
+                );
+            }
+            value
+        };
+        Ok(self)
+    }
+
+    /// Width of the mined region in bits
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of distinct salts, if it fits in a `u64`
+    pub fn capacity(&self) -> Option<u64> {
+        (self.width < 64).then(|| 1u64 << self.width)
+    }
+
+    /// Salt number `index` of the sequential search
+    #[inline]
+    pub fn salt_at(&self, index: u64) -> B256 {
+        let counter = self.start.wrapping_add(U256::from(index));
+        B256::from((self.base | ((counter << self.shift) & self.mask)).to_be_bytes::<32>())
+    }
+
+    /// Salt with uniformly random mined bits
+    #[inline]
+    pub fn random_salt<R: Rng>(&self, rng: &mut R) -> B256 {
+        let random = U256::from_be_bytes(rng.random::<[u8; 32]>());
+        B256::from((self.base | (random & self.mask)).to_be_bytes::<32>())
+    }
+
+    /// First salt of the sequential search
+    pub fn start_salt(&self) -> B256 {
+        self.salt_at(0)
+    }
+
+    /// Fixed part of the salt, zero in the mined region
+    pub fn base_salt(&self) -> B256 {
+        B256::from(self.base.to_be_bytes::<32>())
+    }
+
+    /// Byte range of the salt touched by the mined region
+    pub fn mined_bytes(&self) -> Range<usize> {
+        let first = SALT_NIBBLES - (self.shift + self.width) / 4;
+        let last = SALT_NIBBLES - self.shift / 4;
+        first / 2..last.div_ceil(2)
+    }
+}
+
+impl fmt::Display for SaltTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let hex = alloy::hex::encode(self.base.to_be_bytes::<32>());
+        let first = SALT_NIBBLES - (self.shift + self.width) / 4;
+        let last = SALT_NIBBLES - self.shift / 4;
+        write!(
+            f,
+            "0x{}{}{}",
+            &hex[..first],
+            MINED.to_string().repeat(last - first),
+            &hex[last..]
+        )
+    }
+}
+
+fn low_bits(bits: usize) -> U256 {
+    if bits >= 256 {
+        U256::MAX
+    } else {
+        (U256::from(1) << bits) - U256::from(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::to_bytes32;
+    use alloy::primitives::b256;
+
+    #[test]
+    fn test_default_template_matches_to_bytes32() {
+        let template = SaltTemplate::default();
+        assert_eq!(template.width(), 64);
+        for i in [0, 1, 123, u64::MAX] {
+            assert_eq!(template.salt_at(i), to_bytes32(i));
+        }
+        // Counter wraps within the mined region
+        let template = template.with_start(&u64::MAX.to_string()).unwrap();
+        assert_eq!(template.salt_at(1), to_bytes32(0));
+    }
+
+    #[test]
+    fn test_template_with_fixed_prefix() {
+        let caller = "742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8";
+        let template = SaltTemplate::parse(&format!("0x{}{}", caller, "?".repeat(24))).unwrap();
+        assert_eq!(template.width(), 96);
+        assert_eq!(
+            template.salt_at(0x0102),
+            b256!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8000000000000000000000102")
+        );
+        assert_eq!(
+            template.to_string(),
+            format!("0x{}{}", caller, "?".repeat(24))
+        );
+        assert_eq!(template.mined_bytes(), 20..32);
+        assert_eq!(&template.base_salt()[..20], &template.salt_at(5)[..20]);
+    }
+
+    #[test]
+    fn test_template_with_mined_middle() {
+        let template =
+            SaltTemplate::parse(&format!("0xcafe{}{}", "?".repeat(4), "0".repeat(56))).unwrap();
+        assert_eq!(template.capacity(), Some(1 << 16));
+        assert_eq!(template.mined_bytes(), 2..4);
+        assert_eq!(
+            template.salt_at(0xabcd),
+            b256!("cafeabcd00000000000000000000000000000000000000000000000000000000")
+        );
+        // Wraps modulo 2^16 instead of spilling into fixed nibbles
+        assert_eq!(template.salt_at(0x1_0001), template.salt_at(1));
+
+        let mut rng = rand::rng();
+        let random = template.random_salt(&mut rng);
+        assert_eq!(&random[..2], &[0xca, 0xfe]);
+        assert!(random[4..].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test_full_width_start_salt() {
+        let template = SaltTemplate::parse(&"?".repeat(64))
+            .unwrap()
+            .with_start("0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+            .unwrap();
+        assert_eq!(template.salt_at(0), B256::repeat_byte(0xff));
+        assert_eq!(template.salt_at(1), B256::ZERO);
+    }
+
+    #[test]
+    fn test_start_salt_forms() {
+        let template = SaltTemplate::parse(&format!("0xcafe{}", "?".repeat(60))).unwrap();
+
+        // Counter value in decimal or hex
+        let from_decimal = template.clone().with_start("256").unwrap();
+        let from_hex = template.clone().with_start("0x100").unwrap();
+        assert_eq!(from_decimal.start_salt(), from_hex.start_salt());
+
+        // Full salt must agree with the fixed nibbles
+        let full = format!("0xcafe{}0100", "0".repeat(56));
+        assert_eq!(
+            template.clone().with_start(&full).unwrap().start_salt(),
+            from_hex.start_salt()
+        );
+        let conflicting = format!("0xbeef{}0100", "0".repeat(56));
+        assert!(template.clone().with_start(&conflicting).is_err());
+
+        // Counter value too wide for the mined region
+        assert!(SaltTemplate::default()
+            .with_start("0x10000000000000000")
+            .is_err());
+        assert!(template.with_start("not a number").is_err());
+    }
+
+    #[test]
+    fn test_invalid_templates() {
+        assert!(SaltTemplate::parse("0x????").is_err());
+        assert!(SaltTemplate::parse(&"0".repeat(64)).is_err());
+        assert!(SaltTemplate::parse(&format!("??00??{}", "0".repeat(58))).is_err());
+        assert!(SaltTemplate::parse(&format!("zz{}", "?".repeat(62))).is_err());
+    }
+}
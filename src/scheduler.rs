@@ -0,0 +1,231 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::config::MinerConfig;
+use crate::mining::{process_batch, MinerResult};
+
+/// Wall time a worker aims to spend on one claimed chunk
+const TARGET_CHUNK_TIME: Duration = Duration::from_millis(100);
+
+/// Smallest chunk a worker will claim
+const MIN_CHUNK_SIZE: u64 = 64;
+
+/// Largest chunk a worker will claim, so the found flag and progress stay responsive
+const MAX_CHUNK_SIZE: u64 = 1 << 24;
+
+/// Shared cursor over `start..start + total` salts that workers claim chunks from.
+///
+/// Nothing is materialized up front, so memory stays constant for any iteration count.
+/// Salts wrap around modulo 2^64 once the counter passes `u64::MAX`.
+pub struct SaltCursor {
+    start: u64,
+    total: u64,
+    /// Offset of the next unclaimed salt, never beyond `total`
+    next: AtomicU64,
+}
+
+impl SaltCursor {
+    pub fn new(start: u64, total: u64) -> Self {
+        Self {
+            start,
+            total,
+            next: AtomicU64::new(0),
+        }
+    }
+
+    /// Claim up to `max` salts, returning the first salt and the number claimed
+    pub fn claim(&self, max: u64) -> Option<(u64, u64)> {
+        let max = max.max(1);
+        self.next
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |offset| {
+                (offset < self.total).then(|| offset + max.min(self.total - offset))
+            })
+            .ok()
+            .map(|offset| {
+                let count = max.min(self.total - offset);
+                (self.start.wrapping_add(offset), count)
+            })
+    }
+
+    /// Salts not yet claimed by any worker
+    pub fn remaining(&self) -> u64 {
+        self.total - self.next.load(Ordering::Relaxed)
+    }
+}
+
+/// Per-worker chunk size that adapts to the observed throughput.
+///
+/// Chunks grow or shrink towards [`TARGET_CHUNK_TIME`], and are capped to a fraction of
+/// the remaining work so the tail of a run is spread across all workers.
+pub struct ChunkSizer {
+    size: u64,
+    workers: u64,
+}
+
+impl ChunkSizer {
+    pub fn new(initial: u64, workers: usize) -> Self {
+        Self {
+            size: initial.clamp(MIN_CHUNK_SIZE, MAX_CHUNK_SIZE),
+            workers: workers.max(1) as u64,
+        }
+    }
+
+    /// Size of the next chunk to claim
+    pub fn next_size(&self, remaining: u64) -> u64 {
+        let fair_share = remaining / (2 * self.workers);
+        self.size.min(fair_share.max(MIN_CHUNK_SIZE))
+    }
+
+    /// Adjust the chunk size after `count` salts took `elapsed`
+    pub fn record(&mut self, count: u64, elapsed: Duration) {
+        // Only full-size chunks say anything about throughput at the current size
+        if count < self.size {
+            return;
+        }
+        if elapsed < TARGET_CHUNK_TIME / 2 {
+            self.size = (self.size * 2).min(MAX_CHUNK_SIZE);
+        } else if elapsed > TARGET_CHUNK_TIME * 2 {
+            self.size = (self.size / 2).max(MIN_CHUNK_SIZE);
+        }
+    }
+}
+
+/// Run `process_batch` on every thread of the current rayon pool until the cursor is
+/// exhausted or a match is found. `total_checked` is updated after each chunk.
+pub fn mine(
+    config: &MinerConfig,
+    cursor: &SaltCursor,
+    initial_chunk_size: u64,
+    use_random: bool,
+    found: &AtomicBool,
+    total_checked: &AtomicU64,
+) -> Option<MinerResult> {
+    let result = Mutex::new(None);
+
+    rayon::broadcast(|ctx| {
+        let mut sizer = ChunkSizer::new(initial_chunk_size, ctx.num_threads());
+
+        while !found.load(Ordering::Relaxed) {
+            let Some((start, count)) = cursor.claim(sizer.next_size(cursor.remaining())) else {
+                break;
+            };
+
+            let chunk_start = Instant::now();
+            let batch = process_batch(config, start, count, found, use_random);
+            total_checked.fetch_add(batch.checked, Ordering::Relaxed);
+            sizer.record(count, chunk_start.elapsed());
+
+            if batch.found {
+                result.lock().unwrap().get_or_insert(batch);
+                break;
+            }
+        }
+    });
+
+    result.into_inner().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::CREATE3_PROXY_INITCODE_HASH;
+    use crate::config::{MiningMode, SaltRecipe};
+    use crate::pattern::AddressPattern;
+    use crate::salt::SaltTemplate;
+    use alloy::primitives::address;
+
+    #[test]
+    fn test_cursor_claims_every_salt_once() {
+        let cursor = SaltCursor::new(10, 25);
+
+        assert_eq!(cursor.claim(10), Some((10, 10)));
+        assert_eq!(cursor.claim(10), Some((20, 10)));
+        assert_eq!(cursor.remaining(), 5);
+        assert_eq!(cursor.claim(10), Some((30, 5)));
+        assert_eq!(cursor.claim(10), None);
+        assert_eq!(cursor.remaining(), 0);
+    }
+
+    #[test]
+    fn test_cursor_wraps_around_u64() {
+        let cursor = SaltCursor::new(u64::MAX - 1, u64::MAX);
+
+        assert_eq!(cursor.claim(4), Some((u64::MAX - 1, 4)));
+        // Continues from 2 after wrapping past u64::MAX
+        assert_eq!(cursor.claim(4), Some((2, 4)));
+        assert_eq!(cursor.remaining(), u64::MAX - 8);
+    }
+
+    #[test]
+    fn test_cursor_concurrent_claims_are_disjoint() {
+        let cursor = SaltCursor::new(0, 100_000);
+        let claimed = AtomicU64::new(0);
+
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    while let Some((_, count)) = cursor.claim(37) {
+                        claimed.fetch_add(count, Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(claimed.load(Ordering::Relaxed), 100_000);
+    }
+
+    #[test]
+    fn test_chunk_sizer_adapts() {
+        let mut sizer = ChunkSizer::new(1_000, 4);
+        assert_eq!(sizer.next_size(u64::MAX), 1_000);
+
+        sizer.record(1_000, Duration::from_millis(1));
+        assert_eq!(sizer.next_size(u64::MAX), 2_000);
+
+        sizer.record(2_000, Duration::from_secs(1));
+        assert_eq!(sizer.next_size(u64::MAX), 1_000);
+
+        // Partial chunks don't move the size
+        sizer.record(10, Duration::from_millis(1));
+        assert_eq!(sizer.next_size(u64::MAX), 1_000);
+
+        // Tail of the run is split across workers
+        assert_eq!(sizer.next_size(4_000), 500);
+        assert_eq!(sizer.next_size(10), MIN_CHUNK_SIZE);
+    }
+
+    #[test]
+    fn test_mine_finds_match_and_counts() {
+        let config = MinerConfig {
+            factory_address: address!("742d35cc6bf8632ebc4532fb6d8b2946fbbb85c8"),
+            url_or_bytecode_bytes: vec![0x12; 32],
+            pattern: AddressPattern::prefix("abc", false).unwrap(),
+            mode: MiningMode::Create2,
+            case_sensitive: false,
+            postfix_only: false,
+            dual_matching: false,
+            salt_template: SaltTemplate::default(),
+            salt_recipe: SaltRecipe::Raw,
+            proxy_init_code_hash: CREATE3_PROXY_INITCODE_HASH,
+        };
+
+        let cursor = SaltCursor::new(u64::MAX - 1_000, 1_000_000);
+        let found = AtomicBool::new(false);
+        let total_checked = AtomicU64::new(0);
+        let result = mine(&config, &cursor, 1_000, false, &found, &total_checked).unwrap();
+
+        assert!(result.found);
+        assert!(config.pattern.matches(&result.address.unwrap()));
+        assert!(total_checked.load(Ordering::Relaxed) >= result.checked);
+
+        // Exhausting the range without a match
+        let mut impossible = config.clone();
+        impossible.pattern = AddressPattern::prefix(&"f".repeat(40), false).unwrap();
+        let cursor = SaltCursor::new(0, 5_000);
+        let found = AtomicBool::new(false);
+        let total_checked = AtomicU64::new(0);
+        assert!(mine(&impossible, &cursor, 1_000, false, &found, &total_checked).is_none());
+        assert_eq!(total_checked.load(Ordering::Relaxed), 5_000);
+    }
+}